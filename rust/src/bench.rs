@@ -0,0 +1,56 @@
+use std::{fs, time::Duration};
+
+use crate::puzzles::{Day, Year};
+
+/// Times every implemented part of `days`, reporting the mean and fastest run of each.
+///
+/// The report goes to stderr so that solver output can be discarded by redirecting stdout.
+///
+/// `data/{year}/bench` holds larger synthetic inputs for the grid days, for comparing timings
+/// with `rust bench day13 day14 day16 day17 --data bench`.
+pub fn run(year: &Year, days: &[&Day], data_dir: &str, iterations: usize) {
+    for day in days {
+        let path = year.input_path(data_dir, day);
+        let Ok(data) = fs::read_to_string(&path) else {
+            eprintln!("{} | no input at {path}", day.name);
+            continue;
+        };
+
+        for part in day.parts() {
+            let timings = (0..iterations)
+                .map(|_| day.run(part, &data).unwrap().elapsed)
+                .collect::<Vec<_>>();
+
+            eprintln!("{}", summarize(day.name, part, &timings));
+        }
+    }
+}
+
+fn summarize(name: &str, part: u8, timings: &[Duration]) -> String {
+    let total = timings.iter().sum::<Duration>();
+    let mean = total / timings.len().max(1) as u32;
+    let min = timings.iter().min().copied().unwrap_or_default();
+
+    format!(
+        "{name} part {part} | mean {mean:?} | min {min:?} | {} runs",
+        timings.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    #[test]
+    fn it_summarizes_timings() {
+        let timings = [
+            Duration::from_millis(3),
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+        ];
+        assert_eq!(
+            "day13 part 2 | mean 2ms | min 1ms | 3 runs",
+            summarize("day13", 2, &timings)
+        );
+    }
+}
@@ -1,41 +1,218 @@
+mod alloc_stats;
+mod bench;
+mod progress;
 mod puzzles;
+mod server;
+mod tui;
 mod utils;
 
-use std::fs;
+use std::{fs, path::Path, time::Duration};
 
-use crate::puzzles::*;
+use itertools::Itertools;
+
+use crate::{
+    puzzles::*,
+    utils::{
+        frames::{self, FrameRecorder},
+        image::Rgb,
+        render::Color,
+        Grid2D,
+    },
+};
+
+const DEFAULT_PORT: u16 = 8080;
+const DEFAULT_BENCH_ITERATIONS: usize = 10;
+const DEFAULT_EXPORT_SCALE: usize = 8;
 
 fn main() {
-    let argv = std::env::args().collect::<Vec<_>>();
+    let mut argv = std::env::args().collect::<Vec<_>>();
+    let port = take_flag(&mut argv, "--port")
+        .map(|port| port.parse::<u16>().expect("unable to parse port"))
+        .unwrap_or(DEFAULT_PORT);
+    let year = configured_year(
+        take_flag(&mut argv, "--year")
+            .map(|year| year.parse::<u16>().expect("unable to parse year")),
+    );
+    let Some(year) = find_year(year) else {
+        let known = YEARS.iter().map(|y| y.year.to_string()).collect::<Vec<_>>();
+        eprintln!("unknown year {year}, expected one of: {}", known.join(", "));
+        std::process::exit(1);
+    };
+    let iterations = take_flag(&mut argv, "--iterations")
+        .map(|n| n.parse::<usize>().expect("unable to parse iterations"))
+        .unwrap_or(DEFAULT_BENCH_ITERATIONS);
+    let data_dir = take_flag(&mut argv, "--data").unwrap_or_else(|| "actual".to_owned());
+    let export = take_flag(&mut argv, "--export");
+    let scale = take_flag(&mut argv, "--scale")
+        .map(|scale| scale.parse::<usize>().expect("unable to parse scale"))
+        .unwrap_or(DEFAULT_EXPORT_SCALE);
+    let show = take_switch(&mut argv, "--show");
+    let record = take_flag(&mut argv, "--record");
+    let every = take_flag(&mut argv, "--every")
+        .map(|every| {
+            every
+                .parse::<usize>()
+                .expect("unable to parse frame interval")
+        })
+        .unwrap_or(1);
+    let delay = take_flag(&mut argv, "--delay")
+        .map(|delay| delay.parse::<u64>().expect("unable to parse frame delay"))
+        .map(Duration::from_millis);
 
     match argv.len() {
         1 => panic!("No arguments supplied!"),
+        _ if argv[1] == "serve" => server::run(port, year).expect("server failed"),
+        _ if argv[1] == "tui" => tui::run(year).expect("tui failed"),
+        _ if argv[1] == "bench" => {
+            let days = argv[2..]
+                .iter()
+                .map(|day| year.find_day(day).unwrap_or_else(|| unknown_day(year, day)))
+                .collect::<Vec<_>>();
+
+            bench::run(year, &days, &data_dir, iterations);
+        }
         _ => {
             let day = argv[1].as_str();
-            let data_dir = if let Some(dir) = argv.get(2) { dir } else { "actual" };
-            let data = fs::read_to_string(format!("data/{}/{}.txt", data_dir, day)).unwrap();
-
-            match day {
-                "day1" => day1::solve(data),
-                "day2" => day2::solve(data),
-                "day3" => day3::solve(data),
-                "day4" => day4::solve(data),
-                "day5" => day5::solve(data),
-                "day6" => day6::solve(data),
-                "day7" => day7::solve(data),
-                "day8" => day8::solve(data),
-                "day9" => day9::solve(data),
-                "day10" => day10::solve(data),
-                "day11" => day11::solve(data),
-                "day12" => day12::solve(data),
-                "day13" => day13::solve(data),
-                "day14" => day14::solve(data),
-                "day15" => day15::solve(data),
-                "day16" => day16::solve(data),
-                "day17" => day17::solve(data),
-                "day18" => day18::solve(data),
-                _ => todo!("implement {day}"),
+            let Some(day) = year.find_day(day) else {
+                unknown_day(year, day);
+            };
+
+            let data_dir = if let Some(dir) = argv.get(2) {
+                dir
+            } else {
+                &data_dir
+            };
+            let (data, stats) =
+                alloc_stats::measure(|| fs::read_to_string(year.input_path(data_dir, day)));
+            let data = data.unwrap();
+            if let Some(stats) = stats {
+                println!("Read | {stats}");
+            }
+
+            // solvers parse the input themselves, so each part's stats include this much again
+            if let Some(parse) = day.parse.filter(|_| cfg!(feature = "alloc-stats")) {
+                if let ((), Some(stats)) = alloc_stats::measure(|| parse(&data)) {
+                    println!("Parse | {stats}");
+                }
+            }
+
+            for part in day.parts() {
+                let label = format!("{} part {part}", day.name);
+                let run = || {
+                    progress::with_status_line(&label, || {
+                        alloc_stats::measure(|| day.run(part, &data))
+                    })
+                };
+                let (solution, stats) = match &record {
+                    Some(path) => {
+                        let mut recorder = FrameRecorder::new(FRAME_PALETTE.to_vec())
+                            .with_interval(every)
+                            .with_scale(scale);
+                        if let Some(delay) = delay {
+                            recorder = recorder.with_delay(delay);
+                        }
+                        let (result, recorder) = frames::with_recorder(recorder, run);
+                        save_frames(&recorder, path, part);
+                        result
+                    }
+                    None => run(),
+                };
+                let solution = solution.unwrap();
+                let stats = stats.map(|stats| format!(" [{stats}]")).unwrap_or_default();
+                println!(
+                    "Part{part} Answer | {} ({:?}){stats}",
+                    solution.answer, solution.elapsed
+                );
+            }
+
+            if let Some(path) = export {
+                let Some(grid) = day.grid else {
+                    panic!("{} has no grid to export", day.name);
+                };
+                grid(&data)
+                    .save_image(&path, scale, tile_color)
+                    .unwrap_or_else(|err| panic!("unable to export {path}: {err}"));
+                println!("Grid | {path}");
+            }
+
+            if show {
+                let Some(grid) = day.grid else {
+                    panic!("{} has no grid to show", day.name);
+                };
+                show_grid(&grid(&data));
             }
         }
     }
 }
+
+/// Saves the frames recorded while running `part` next to `path`, as `name-part1.gif` for a
+/// `name.gif` path or `name-part1` for a directory of PPMs.
+fn save_frames(recorder: &FrameRecorder, path: &str, part: u8) {
+    if recorder.is_empty() {
+        return;
+    }
+
+    let path = Path::new(path);
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut name = format!("{stem}-part{part}");
+    if let Some(extension) = path.extension() {
+        name = format!("{name}.{}", extension.to_string_lossy());
+    }
+
+    let path = path.with_file_name(name);
+    recorder
+        .save(&path)
+        .unwrap_or_else(|err| panic!("unable to save frames to {}: {err}", path.display()));
+    println!("Frames | {} saved to {}", recorder.len(), path.display());
+}
+
+/// Prints `grid` in the colors it's exported in, leaving empty space uncolored.
+fn show_grid(grid: &Grid2D<char>) {
+    let mut shown = grid.highlighted(|&ch| ch);
+    for ch in grid
+        .iter()
+        .map(|(_, &ch)| ch)
+        .filter(|&ch| ch != '.')
+        .unique()
+    {
+        let Rgb(r, g, b) = tile_color(&ch);
+        shown = shown.layer(
+            grid.find_all(move |&cell| cell == ch),
+            Color::Rgb { r, g, b },
+        );
+    }
+
+    println!("{shown}");
+}
+
+/// Reports a day that `year` doesn't have, along with the ones it does, and exits.
+fn unknown_day(year: &Year, name: &str) -> ! {
+    let known = year.days.iter().map(|day| day.name).collect::<Vec<_>>();
+    eprintln!(
+        "unknown day {name} in {}, expected one of: {}",
+        year.year,
+        known.join(", ")
+    );
+    std::process::exit(1);
+}
+
+/// Removes `--flag` from the arguments, returning whether it was passed.
+fn take_switch(argv: &mut Vec<String>, flag: &str) -> bool {
+    let Some(idx) = argv.iter().position(|arg| arg == flag) else {
+        return false;
+    };
+
+    argv.remove(idx);
+    true
+}
+
+/// Removes `--flag value` from the arguments, returning the value if the flag was passed.
+fn take_flag(argv: &mut Vec<String>, flag: &str) -> Option<String> {
+    let idx = argv.iter().position(|arg| arg == flag)?;
+    let value = argv
+        .get(idx + 1)
+        .unwrap_or_else(|| panic!("{flag} requires a value"))
+        .clone();
+    argv.drain(idx..=idx + 1);
+    Some(value)
+}
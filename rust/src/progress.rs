@@ -0,0 +1,144 @@
+use std::{
+    cell::RefCell,
+    io::{self, IsTerminal, Write},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::utils::scoped;
+
+const BAR_WIDTH: usize = 30;
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+type Reporter = Box<dyn FnMut(usize, usize)>;
+
+thread_local! {
+    static REPORTER: RefCell<Option<Reporter>> = const { RefCell::new(None) };
+}
+
+/// Reports that `done` out of `total` items of the running part have been processed.
+///
+/// Solvers can call this as often as they like; it does nothing unless the runner is listening.
+pub fn report(done: usize, total: usize) {
+    REPORTER.with(|reporter| {
+        if let Some(reporter) = reporter.borrow_mut().as_mut() {
+            reporter(done, total);
+        }
+    });
+}
+
+/// Runs `f`, passing every progress report it makes on this thread to `reporter`.
+pub fn with_reporter<T>(reporter: impl FnMut(usize, usize) + 'static, f: impl FnOnce() -> T) -> T {
+    scoped::with_hook(&REPORTER, Box::new(reporter), f).0
+}
+
+/// Runs `f` while drawing its progress as a single status line on stderr, or silently if stderr
+/// isn't a terminal.
+pub fn with_status_line<T>(label: &str, f: impl FnOnce() -> T) -> T {
+    let stderr = io::stderr();
+    if !stderr.is_terminal() {
+        return f();
+    }
+
+    let status = Rc::new(RefCell::new(StatusLine::new(label, stderr)));
+    let reporter = Rc::clone(&status);
+    let result = with_reporter(
+        move |done, total| reporter.borrow_mut().update(done, total),
+        f,
+    );
+    status.borrow_mut().clear();
+    result
+}
+
+struct StatusLine<W: Write> {
+    label: String,
+    out: W,
+    last_draw: Option<Instant>,
+    drawn: bool,
+}
+
+impl<W: Write> StatusLine<W> {
+    fn new(label: &str, out: W) -> Self {
+        Self {
+            label: label.to_owned(),
+            out,
+            last_draw: None,
+            drawn: false,
+        }
+    }
+
+    fn update(&mut self, done: usize, total: usize) {
+        let due = self
+            .last_draw
+            .is_none_or(|last| last.elapsed() >= REDRAW_INTERVAL);
+        if !due && done < total {
+            return;
+        }
+
+        self.last_draw = Some(Instant::now());
+        self.drawn = true;
+        let _ = write!(self.out, "\r\x1b[2K{}", render(&self.label, done, total));
+        let _ = self.out.flush();
+    }
+
+    fn clear(&mut self) {
+        if self.drawn {
+            let _ = write!(self.out, "\r\x1b[2K");
+            let _ = self.out.flush();
+        }
+    }
+}
+
+fn render(label: &str, done: usize, total: usize) -> String {
+    let done = done.min(total);
+    // an empty workload is as done as it'll ever be
+    let filled = (BAR_WIDTH * done).checked_div(total).unwrap_or(BAR_WIDTH);
+    let percent = (100 * done).checked_div(total).unwrap_or(100);
+
+    format!(
+        "{label} [{}{}] {done}/{total} {percent}%",
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::progress::*;
+
+    #[test]
+    fn it_forwards_reports_to_the_reporter() {
+        let reports = Rc::new(RefCell::new(vec![]));
+        let reporter = Rc::clone(&reports);
+        with_reporter(
+            move |done, total| reporter.borrow_mut().push((done, total)),
+            || {
+                report(1, 3);
+                report(3, 3);
+            },
+        );
+
+        // nothing is listening once the reporter has been removed
+        report(2, 3);
+        assert_eq!(vec![(1, 3), (3, 3)], *reports.borrow());
+    }
+
+    #[test]
+    fn it_renders_a_status_line() {
+        assert_eq!(
+            "day16 part 2 [###############---------------] 5/10 50%",
+            render("day16 part 2", 5, 10)
+        );
+
+        let mut status = StatusLine::new("day12 part 1", vec![]);
+        status.update(1, 4);
+        // throttled, since it's too soon after the last draw
+        status.update(2, 4);
+        status.update(4, 4);
+        status.clear();
+
+        let out = String::from_utf8(status.out).unwrap();
+        assert_eq!(2, out.matches("day12 part 1").count());
+        assert!(out.ends_with("4/4 100%\r\x1b[2K"));
+    }
+}
@@ -1,18 +1,141 @@
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-pub mod day10;
-pub mod day11;
-pub mod day12; 
-pub mod day13; 
-pub mod day14; 
-pub mod day15; 
-pub mod day16; 
-pub mod day17; 
-pub mod day18; 
+use std::time::{Duration, Instant};
+
+use crate::utils::{image::Rgb, Grid2D};
+
+pub mod y2023;
+
+pub type Solver = fn(&str) -> String;
+
+pub type GridRenderer = fn(&str) -> Grid2D<char>;
+
+pub type Parser = fn(&str);
+
+/// The color a tile from a [`GridRenderer`] is exported as.
+///
+/// Renderers share a loose vocabulary: `.` is empty space, `#` is walls or filled cells, `I`
+/// marks enclosed cells and `O` round rocks, while anything else is drawn as a foreground piece.
+pub fn tile_color(&ch: &char) -> Rgb {
+    match ch {
+        '.' => Rgb(24, 24, 37),
+        '#' => Rgb(250, 179, 135),
+        'I' => Rgb(166, 227, 161),
+        'O' => Rgb(205, 214, 244),
+        _ => Rgb(137, 180, 250),
+    }
+}
+
+/// The palette frames are recorded in: empty space, fixed pieces, moving or lit cells and
+/// highlights, in that order. Solvers pick one of these indices for every cell they record.
+pub const FRAME_PALETTE: [Rgb; 4] = [
+    Rgb(24, 24, 37),
+    Rgb(250, 179, 135),
+    Rgb(205, 214, 244),
+    Rgb(166, 227, 161),
+];
+
+/// A registered day along with the solvers for each of its implemented parts.
+pub struct Day {
+    pub name: &'static str,
+    pub part1: Solver,
+    pub part2: Option<Solver>,
+    /// Renders the day's grid state for an input, for days that are solved on a `Grid2D`.
+    pub grid: Option<GridRenderer>,
+    /// Runs just the parsing that part 1 starts with, throwing the result away, so that its cost
+    /// can be measured apart from the solving.
+    pub parse: Option<Parser>,
+}
+
+pub struct Solution {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+impl Day {
+    const fn new(name: &'static str, part1: Solver, part2: Option<Solver>) -> Self {
+        Self {
+            name,
+            part1,
+            part2,
+            grid: None,
+            parse: None,
+        }
+    }
+
+    const fn with_grid(self, grid: GridRenderer) -> Self {
+        Self {
+            grid: Some(grid),
+            ..self
+        }
+    }
+
+    const fn with_parse(self, parse: Parser) -> Self {
+        Self {
+            parse: Some(parse),
+            ..self
+        }
+    }
+
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        (1..=2)
+            .filter(|&part| self.solver(part).is_some())
+            .collect()
+    }
+
+    /// Runs the given part against `input`, returning `None` if the part isn't implemented.
+    pub fn run(&self, part: u8, input: &str) -> Option<Solution> {
+        let solver = self.solver(part)?;
+        let start = Instant::now();
+        let answer = solver(input);
+        Some(Solution {
+            answer,
+            elapsed: start.elapsed(),
+        })
+    }
+}
+
+/// The year used when none is passed with `--year` or set through `AOC_YEAR`.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// An event's worth of days. Solvers live in `puzzles::y{year}` and inputs in `data/{year}`.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    /// Looks up a day by name, accepting either `day6` or plain `6`.
+    pub fn find_day(&self, name: &str) -> Option<&'static Day> {
+        self.days
+            .iter()
+            .find(|day| day.name == name || day.name.strip_prefix("day") == Some(name))
+    }
+
+    pub fn input_path(&self, data_dir: &str, day: &Day) -> String {
+        format!("data/{}/{}/{}.txt", self.year, data_dir, day.name)
+    }
+}
+
+pub const YEARS: &[Year] = &[Year {
+    year: 2023,
+    days: y2023::DAYS,
+}];
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// Resolves the year to use, preferring an explicit `--year` over the `AOC_YEAR` environment
+/// variable and falling back to [`DEFAULT_YEAR`].
+pub fn configured_year(explicit: Option<u16>) -> u16 {
+    explicit
+        .or_else(|| std::env::var("AOC_YEAR").ok()?.parse::<u16>().ok())
+        .unwrap_or(DEFAULT_YEAR)
+}
@@ -0,0 +1,47 @@
+use super::Day;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+pub const DAYS: &[Day] = &[
+    Day::new("day1", day1::part1, None),
+    Day::new("day2", day2::part1, None),
+    Day::new("day3", day3::part1, Some(day3::part2)),
+    Day::new("day4", day4::part1, Some(day4::part2)),
+    Day::new("day5", day5::part1, Some(day5::part2)).with_parse(day5::parse),
+    Day::new("day6", day6::part1, Some(day6::part2)),
+    Day::new("day7", day7::part1, None),
+    Day::new("day8", day8::part1, Some(day8::part2)),
+    Day::new("day9", day9::part1, Some(day9::part2)),
+    Day::new("day10", day10::part1, Some(day10::part2)).with_grid(day10::grid),
+    Day::new("day11", day11::part1, Some(day11::part2)),
+    Day::new("day12", day12::part1, Some(day12::part2)),
+    Day::new("day13", day13::part1, Some(day13::part2)),
+    Day::new("day14", day14::part1, Some(day14::part2))
+        .with_grid(day14::grid)
+        .with_parse(day14::parse),
+    Day::new("day15", day15::part1, Some(day15::part2)),
+    Day::new("day16", day16::part1, Some(day16::part2))
+        .with_grid(day16::grid)
+        .with_parse(day16::parse),
+    Day::new("day17", day17::part1, None).with_parse(day17::parse),
+    Day::new("day18", day18::part1, Some(day18::part2))
+        .with_grid(day18::grid)
+        .with_parse(day18::parse),
+];
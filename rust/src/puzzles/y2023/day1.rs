@@ -3,14 +3,14 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
-pub fn solve(content: String) {
+pub fn part1(content: &str) -> String {
     let sum: u16 = content
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| parse_digits(line))
         .sum();
 
-    println!("sum of calibration values: {sum}");
+    sum.to_string()
 }
 
 lazy_static! {
@@ -50,7 +50,5 @@ fn parse_digits(line: &str) -> u16 {
         }
     }
 
-    println!("{str}");
-
     return str.parse::<u16>().unwrap();
 }
@@ -2,55 +2,71 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-pub fn solve(content: String) {
-    part1(&content);
-    part2(&content);
-}
+use crate::utils::{geometry::interior_points, render::box_drawing, Direction4, Grid2D, Point};
 
-fn part1(content: &String) {
+pub fn part1(content: &str) -> String {
     let actual_loop = get_loop(content);
     let farthest_step = actual_loop.len() / 2;
-    println!("Part1 | The farthest point is {farthest_step} steps away");
+    farthest_step.to_string()
 }
 
-fn part2(content: &String) {
+pub fn part2(content: &str) -> String {
     let actual_loop = get_loop(content);
-    let loop_points = actual_loop
+    let corners = actual_loop
         .iter()
-        .map(|entry| (entry.pos, entry))
-        .collect::<HashMap<_, _>>();
+        .map(|entry| (entry.pos.x as i64, entry.pos.y as i64))
+        .collect::<Vec<_>>();
+    interior_points(&corners).to_string()
+}
 
-    // get loop boundaries
-    let min_x = actual_loop.iter().map(|entry| entry.pos.0).min().unwrap();
-    let max_x = actual_loop.iter().map(|entry| entry.pos.0).max().unwrap();
+/// Renders the main loop in box-drawing characters, with every tile it encloses marked as `I`.
+pub fn grid(content: &str) -> Grid2D<char> {
+    let tiles = Grid2D::from_lines(content.lines());
+    let mut grid = Grid2D::with_size(tiles.width(), tiles.height(), '.');
 
-    let min_y = actual_loop.iter().map(|entry| entry.pos.1).min().unwrap();
-    let max_y = actual_loop.iter().map(|entry| entry.pos.1).max().unwrap();
+    let actual_loop = get_loop(content);
+    for entry in actual_loop.iter() {
+        let (x, y) = (entry.pos.x, entry.pos.y);
+        grid.replace(box_drawing(*tiles.get(x, y).unwrap()), x, y);
+    }
 
-    // generate matrix around loop boundaries
-    let mut point_matrix = HashMap::new();
+    for point in enclosed_points(&actual_loop) {
+        grid.replace('I', point.x, point.y);
+    }
 
-    for y in min_y..=max_y {
-        let mut is_inside = false;
-        for x in min_x..=max_x {
-            let point = Point::new(x, y);
-            if let Some(loop_point) = loop_points.get(&point) {
-                if loop_point.direction.uses_map_direction(MapDirection::North) {
-                    is_inside = !is_inside;
-                }
-            } else if is_inside {
-                *point_matrix.entry(point).or_insert(0_usize) += 1;
-            }
-        }
+    grid
+}
+
+/// The tiles walled in by the loop, found by flooding in from the edge on a grid at twice the
+/// resolution, so that the gaps between pipes that run side by side can be squeezed through.
+fn enclosed_points(actual_loop: &[Pipe]) -> Vec<Point> {
+    let Some(width) = actual_loop.iter().map(|entry| entry.pos.x + 1).max() else {
+        return vec![];
+    };
+    let height = actual_loop
+        .iter()
+        .map(|entry| entry.pos.y + 1)
+        .max()
+        .unwrap();
+
+    // each tile of the loop, and the half step joining it to the next one
+    let mut walls = Grid2D::with_size(2 * width, 2 * height, false);
+    for (entry, next) in actual_loop.iter().zip(actual_loop.iter().cycle().skip(1)) {
+        walls.replace(true, 2 * entry.pos.x, 2 * entry.pos.y);
+        walls.replace(true, entry.pos.x + next.pos.x, entry.pos.y + next.pos.y);
     }
 
-    // count entries in the point matrix that have a value of 2 (i.e., were marked as in the border
-    // twice)
-    let area = point_matrix.into_values().filter(|v| v == &1).count();
-    println!("Area enclosed by loop is {area}");
+    let outside = walls.exterior_fill(|&wall| !wall);
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+        .filter(|point| {
+            let (x, y) = (2 * point.x, 2 * point.y);
+            walls.get(x, y) == Some(&false) && outside.get(x, y) == Some(&false)
+        })
+        .collect()
 }
 
-fn get_loop(content: &String) -> Vec<Pipe> {
+fn get_loop(content: &str) -> Vec<Pipe> {
     let map = parse_content(content);
     let starting_point = get_starting_point(&map);
     let mut potential_loops = get_potential_loops(&starting_point, &map);
@@ -63,8 +79,6 @@ fn get_loop(content: &String) -> Vec<Pipe> {
             let last = l.last().unwrap();
             // get neighbours
             let neighbors = last.get_neighbors(&map);
-            // println!("Last: {:?}", last);
-            // println!("Valid Neighbors: {:?}", neighbors);
             // except the previous entry (or starting point)
             let prev_entry = l.iter().rev().skip(1).take(1).copied().collect::<Vec<_>>();
 
@@ -144,7 +158,7 @@ fn get_loop(content: &String) -> Vec<Pipe> {
     return actual_loop;
 }
 
-fn parse_content(content: &String) -> HashMap<Point, Pipe> {
+fn parse_content(content: &str) -> HashMap<Point, Pipe> {
     content
         .lines()
         .enumerate()
@@ -204,26 +218,15 @@ enum Direction {
     None,
 }
 
-enum MapDirection {
-    North,
-    South,
-    East,
-    West,
-}
-
 impl Direction {
-    fn uses_map_direction(&self, dir: MapDirection) -> bool {
+    fn uses_map_direction(&self, dir: Direction4) -> bool {
         match dir {
-            MapDirection::East if self == &Self::NE || self == &Self::SE || self == &Self::EW => {
-                true
-            }
-            MapDirection::West if self == &Self::NW || self == &Self::SW || self == &Self::EW => {
+            Direction4::East if self == &Self::NE || self == &Self::SE || self == &Self::EW => true,
+            Direction4::West if self == &Self::NW || self == &Self::SW || self == &Self::EW => true,
+            Direction4::South if self == &Self::NS || self == &Self::SE || self == &Self::SW => {
                 true
             }
-            MapDirection::South if self == &Self::NS || self == &Self::SE || self == &Self::SW => {
-                true
-            }
-            MapDirection::North if self == &Self::NE || self == &Self::NW || self == &Self::NS => {
+            Direction4::North if self == &Self::NE || self == &Self::NW || self == &Self::NS => {
                 true
             }
             _ => self == &Self::Unknown,
@@ -246,15 +249,6 @@ impl From<char> for Direction {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
-struct Point(usize, usize);
-
-impl Point {
-    fn new(x: usize, y: usize) -> Self {
-        Self(x, y)
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 struct Pipe {
     pos: Point,
@@ -267,38 +261,38 @@ impl Pipe {
     }
 
     fn connects(&self, other: &Self) -> bool {
-        if self.pos.1 == other.pos.1 {
+        if self.pos.y == other.pos.y {
             // compare east and west directions
-            if self.pos.0 < other.pos.0 {
+            if self.pos.x < other.pos.x {
                 // other is to the east of self
-                if self.direction.uses_map_direction(MapDirection::East)
-                    && other.direction.uses_map_direction(MapDirection::West)
+                if self.direction.uses_map_direction(Direction4::East)
+                    && other.direction.uses_map_direction(Direction4::West)
                 {
                     return true;
                 }
             } else {
                 // other is to the west of self
-                if self.direction.uses_map_direction(MapDirection::West)
-                    && other.direction.uses_map_direction(MapDirection::East)
+                if self.direction.uses_map_direction(Direction4::West)
+                    && other.direction.uses_map_direction(Direction4::East)
                 {
                     return true;
                 }
             }
         }
 
-        if self.pos.0 == other.pos.0 {
+        if self.pos.x == other.pos.x {
             // compare north and south directions
-            if self.pos.1 < other.pos.1 {
+            if self.pos.y < other.pos.y {
                 // other is to the south of self
-                if self.direction.uses_map_direction(MapDirection::South)
-                    && other.direction.uses_map_direction(MapDirection::North)
+                if self.direction.uses_map_direction(Direction4::South)
+                    && other.direction.uses_map_direction(Direction4::North)
                 {
                     return true;
                 }
             } else {
                 // other is to the north of self
-                if self.direction.uses_map_direction(MapDirection::North)
-                    && other.direction.uses_map_direction(MapDirection::South)
+                if self.direction.uses_map_direction(Direction4::North)
+                    && other.direction.uses_map_direction(Direction4::South)
                 {
                     return true;
                 }
@@ -310,18 +304,18 @@ impl Pipe {
 
     fn get_neighbors(&self, map: &HashMap<Point, Pipe>) -> Vec<Pipe> {
         let mut neighbors = vec![];
-        if self.pos.0 > 0 {
+        if self.pos.x > 0 {
             // must be a West facing pipe
-            let point = Point::new(self.pos.0 - 1, self.pos.1);
+            let point = Point::new(self.pos.x - 1, self.pos.y);
             if let Some(pipe) = map.get(&point) {
                 if self.connects(&pipe) {
                     neighbors.push(pipe.clone());
                 }
             }
         }
-        if self.pos.1 > 0 {
+        if self.pos.y > 0 {
             // must be a South facing pipe
-            let point = Point::new(self.pos.0, self.pos.1 - 1);
+            let point = Point::new(self.pos.x, self.pos.y - 1);
             if let Some(pipe) = map.get(&point) {
                 if self.connects(&pipe) {
                     neighbors.push(pipe.clone());
@@ -330,7 +324,7 @@ impl Pipe {
         }
 
         // must be a North facing pipe
-        let point = Point::new(self.pos.0, self.pos.1 + 1);
+        let point = Point::new(self.pos.x, self.pos.y + 1);
         if let Some(pipe) = map.get(&point) {
             if self.connects(&pipe) {
                 neighbors.push(pipe.clone());
@@ -338,7 +332,7 @@ impl Pipe {
         }
 
         // must be an East facing pipe
-        let point = Point::new(self.pos.0 + 1, self.pos.1);
+        let point = Point::new(self.pos.x + 1, self.pos.y);
         if let Some(pipe) = map.get(&point) {
             if self.connects(&pipe) {
                 neighbors.push(pipe.clone());
@@ -348,3 +342,25 @@ impl Pipe {
         return neighbors;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzles::y2023::day10::*;
+    use std::fs;
+
+    #[test]
+    fn it_counts_enclosed_tiles() {
+        // the answer and the rendered grid come from different helpers, so check they agree,
+        // including when the starting tile hides which way the loop turns
+        let content = fs::read_to_string("data/2023/test/day10.txt").unwrap();
+        assert_eq!("8", part2(&content));
+        assert_eq!(8, grid(&content).find_all(|&ch| ch == 'I').count());
+        assert!(enclosed_points(&[]).is_empty());
+
+        // the tiles between the two pipes running down the middle are outside the loop
+        let squeezed = "..........\n.S------7.\n.|F----7|.\n.||....||.\n.||....||.\n\
+                        .|L-7F-J|.\n.|..||..|.\n.L--JL--J.\n..........";
+        assert_eq!("4", part2(squeezed));
+        assert_eq!(4, grid(squeezed).find_all(|&ch| ch == 'I').count());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::utils::Point;
+
+pub fn part1(content: &str) -> String {
+    let mut galaxies = parse_galaxies(content);
+    expand_galaxies(&mut galaxies, 1);
+
+    let total_steps: usize = galaxies
+        .iter()
+        .enumerate()
+        .map(|(i, a)| galaxies.iter().skip(i + 1).map(|b| a.distance(b)))
+        .flatten()
+        .sum();
+
+    total_steps.to_string()
+}
+
+pub fn part2(content: &str) -> String {
+    let mut galaxies = parse_galaxies(content);
+    expand_galaxies(&mut galaxies, 1_000_000 - 1);
+
+    let total_steps: usize = galaxies
+        .iter()
+        .enumerate()
+        .map(|(i, a)| galaxies.iter().skip(i + 1).map(|b| a.distance(b)))
+        .flatten()
+        .sum();
+
+    total_steps.to_string()
+}
+
+fn parse_galaxies(content: &str) -> Vec<Galaxy> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, ch)| ch == &'#')
+                .map(|(x, _)| Galaxy::new(x, y))
+                .collect::<Vec<_>>()
+        })
+        .flatten()
+        .collect::<Vec<_>>()
+}
+
+fn expand_galaxies(galaxies: &mut Vec<Galaxy>, expansion_amount: usize) {
+    let min_x = galaxies.iter().map(|g| g.original_pos.x).min().unwrap();
+    let max_x = galaxies.iter().map(|g| g.original_pos.x).max().unwrap();
+
+    let min_y = galaxies.iter().map(|g| g.original_pos.y).min().unwrap();
+    let max_y = galaxies.iter().map(|g| g.original_pos.y).max().unwrap();
+
+    let xs_to_expand = (min_x + 1..max_x)
+        .filter(|x| galaxies.iter().filter(|&g| g.original_pos.x == *x).count() == 0)
+        .collect::<Vec<_>>();
+
+    let ys_to_expand = (min_y + 1..max_y)
+        .filter(|y| galaxies.iter().filter(|&g| g.original_pos.y == *y).count() == 0)
+        .collect::<Vec<_>>();
+
+    for x in xs_to_expand {
+        for g in galaxies.iter_mut().filter(|g| g.original_pos.x > x) {
+            g.current_pos.x += expansion_amount;
+        }
+    }
+
+    for y in ys_to_expand {
+        for g in galaxies.iter_mut().filter(|g| g.original_pos.y > y) {
+            g.current_pos.y += expansion_amount;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Galaxy {
+    original_pos: Point,
+    current_pos: Point,
+}
+
+impl Galaxy {
+    fn new(x: usize, y: usize) -> Self {
+        let point = Point::new(x, y);
+        Self {
+            original_pos: point.clone(),
+            current_pos: point.clone(),
+        }
+    }
+
+    fn distance(&self, other: &Self) -> usize {
+        self.current_pos.manhattan_distance(other.current_pos)
+    }
+}
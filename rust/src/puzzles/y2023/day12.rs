@@ -1,16 +1,14 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 
-pub fn solve(content: String) {
-    part1(&content);
-    part2(&content);
-}
-
-fn part1(content: &String) {
-    let count_matches: u64 = content
-        .lines()
-        .map(|line| {
-            println!("Processing line: {line}");
+use crate::progress;
+
+pub fn part1(content: &str) -> String {
+    let lines = content.lines().collect::<Vec<_>>();
+    let count_matches: u64 = lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
             let parts = line.split(' ').collect::<Vec<_>>();
             let pattern = parts[0];
             let nums = parts[1]
@@ -19,18 +17,20 @@ fn part1(content: &String) {
                 .collect::<Vec<_>>();
 
             let total_matches = shite_2(pattern, &nums[..], &mut HashMap::new());
-            println!("{total_matches}");
+            progress::report(idx + 1, lines.len());
             return total_matches;
         })
         .sum();
 
-    println!("total matches: {count_matches}");
+    count_matches.to_string()
 }
 
-fn part2(content: &String) {
-    let count_matches: u64 = content
-        .lines()
-        .map(|line| {
+pub fn part2(content: &str) -> String {
+    let lines = content.lines().collect::<Vec<_>>();
+    let count_matches: u64 = lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
             let parts = line.split(' ').collect::<Vec<_>>();
             let pattern = parts[0];
             let nums = parts[1]
@@ -42,11 +42,12 @@ fn part2(content: &String) {
             let repeated_nums = nums.repeat(5);
 
             let total_matches = shite_2(&repeated_pattern, &repeated_nums[..], &mut HashMap::new());
+            progress::report(idx + 1, lines.len());
             return total_matches;
         })
         .sum();
 
-    println!("total matches: {count_matches}");
+    count_matches.to_string()
 }
 
 fn shite_2(p: &str, groups: &[usize], memo: &mut HashMap<(usize, usize), u64>) -> u64 {
@@ -0,0 +1,49 @@
+use crate::utils::{BitGrid, Grid2D};
+
+pub fn part1(content: &str) -> String {
+    let mut answer = 0;
+    for pattern in content.split("\n\n") {
+        let grid = BitGrid::from_grid(&Grid2D::from_lines(pattern.lines()), '#');
+        answer += reflection_score(&grid, 0);
+    }
+
+    answer.to_string()
+}
+
+pub fn part2(content: &str) -> String {
+    let mut answer = 0;
+    for pattern in content.split("\n\n") {
+        let grid = BitGrid::from_grid(&Grid2D::from_lines(pattern.lines()), '#');
+        answer += reflection_score(&grid, 1);
+    }
+
+    answer.to_string()
+}
+
+/// Scores the mirror line that the pattern reflects across once exactly `smudges` cells are
+/// fixed, checking vertical lines before horizontal ones.
+fn reflection_score(grid: &BitGrid, smudges: u32) -> u64 {
+    for i in 0..grid.width() - 1 {
+        if mismatches_across(i, grid.width(), |a, b| grid.col_mismatches(a, b)) == smudges {
+            return i as u64 + 1;
+        }
+    }
+
+    for i in 0..grid.height() - 1 {
+        if mismatches_across(i, grid.height(), |a, b| grid.row_mismatches(a, b)) == smudges {
+            return (i as u64 + 1) * 100;
+        }
+    }
+
+    0
+}
+
+/// Counts the cells that differ between the lines either side of the mirror between lines
+/// `index` and `index + 1`, out of `len` lines.
+fn mismatches_across(index: usize, len: usize, mismatches: impl Fn(usize, usize) -> u32) -> u32 {
+    (0..=index)
+        .rev()
+        .zip(index + 1..len)
+        .map(|(before, after)| mismatches(before, after))
+        .sum()
+}
@@ -0,0 +1,118 @@
+use std::hint::black_box;
+
+use crate::utils::{cycle::find_cycle_by_key, frames, Direction4, Grid2D, Point};
+
+pub fn part1(content: &str) -> String {
+    let platform = grid(content);
+    let total_load = calculate_load(&platform);
+    total_load.to_string()
+}
+
+/// Renders the platform after tilting it north, as in part 1.
+pub fn grid(content: &str) -> Grid2D<char> {
+    let mut platform = Grid2D::from_lines(content.lines());
+    tilt_north(&mut platform);
+    platform
+}
+
+/// Reads the platform without tilting it.
+pub fn parse(content: &str) {
+    black_box(Grid2D::from_lines(content.lines()));
+}
+
+const TOTAL_ITERATIONS: usize = 1_000_000_000;
+
+pub fn part2(content: &str) -> String {
+    let platform = Grid2D::from_lines(content.lines());
+    let spun = |platform: &Grid2D<char>| {
+        let mut platform = platform.clone();
+        spin_cycle(&mut platform);
+        platform
+    };
+
+    // the platform soon settles into a loop, so every state we need is seen before it repeats
+    let (cycle, states) = find_cycle_by_key(platform, spun, Grid2D::fingerprint);
+    let total_load = calculate_load(&states[cycle.first_index(TOTAL_ITERATIONS)]);
+    total_load.to_string()
+}
+
+fn calculate_load(platform: &Grid2D<char>) -> usize {
+    (0..platform.height())
+        .map(|y| {
+            let row_num = platform.height() - y;
+            let num_round_rocks = platform.row_iterator(y).filter(|&x| x == &'O').count();
+            num_round_rocks * row_num
+        })
+        .sum::<usize>()
+}
+
+/// Tilts the platform north, then west, then south, then east.
+fn spin_cycle(platform: &mut Grid2D<char>) {
+    // turning the platform clockwise brings the next direction round to the north
+    for _ in 0..4 {
+        tilt_north(platform);
+        *platform = platform.rotate_cw();
+    }
+
+    frames::record(platform, |&ch| match ch {
+        '#' => 1,
+        'O' => 2,
+        _ => 0,
+    });
+}
+
+fn tilt_north(platform: &mut Grid2D<char>) {
+    for y in 1..platform.height() {
+        for x in 0..platform.width() {
+            do_shit(platform, x, y);
+        }
+    }
+}
+
+fn do_shit(platform: &mut Grid2D<char>, x: usize, y: usize) {
+    let item = platform.get(x, y).unwrap();
+    if item == &'O' {
+        // roll until we hit an unmovable object or the edge of the platform
+        let old_pos = Point::new(x, y);
+        let mut pos = old_pos;
+        while let Some(next) = platform.step(pos, Direction4::North) {
+            if platform.get(next.x, next.y) != Some(&'.') {
+                break;
+            }
+            pos = next;
+        }
+
+        if pos != old_pos {
+            platform.replace('O', pos.x, pos.y);
+            platform.replace('.', old_pos.x, old_pos.y);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_grid_eq, puzzles::y2023::day14::*};
+    use std::fs;
+
+    #[test]
+    fn it_spins_the_platform() {
+        let content = fs::read_to_string("data/2023/test/day14.txt").unwrap();
+        let mut platform = Grid2D::from_lines(content.lines());
+        spin_cycle(&mut platform);
+
+        let expected = [
+            ".....#....",
+            "....#...O#",
+            "...OO##...",
+            ".OO#......",
+            ".....OOO#.",
+            ".O#...O#.#",
+            "....O#....",
+            "......OOOO",
+            "#...O###..",
+            "#..OO#....",
+        ]
+        .join("\n");
+        assert_grid_eq!(Grid2D::from_lines(expected.lines()), platform);
+    }
+}
@@ -1,9 +1,4 @@
-pub fn solve(content: String) {
-    part1(&content);
-    part2(&content);
-}
-
-fn part1(content: &String) {
+pub fn part1(content: &str) -> String {
     let verification_number: u64 = content
         .split(',')
         .filter(|x| !x.is_empty())
@@ -14,10 +9,10 @@ fn part1(content: &String) {
         })
         .sum();
 
-    println!("Verification number: {verification_number}");
+    verification_number.to_string()
 }
 
-fn part2(content: &String) {
+pub fn part2(content: &str) -> String {
     let instructions = content
         .split(',')
         .filter(|x| !x.is_empty())
@@ -88,7 +83,7 @@ fn part2(content: &String) {
         })
         .sum();
 
-    println!("Total focusing power: {total_focusing_power}");
+    total_focusing_power.to_string()
 }
 
 struct Lens {
@@ -1,52 +1,70 @@
-use std::fmt::{Display, Write};
-
-use crate::utils::Grid2D;
-
-pub fn solve(content: String) {
-    part1(&content);
-    part2(&content);
-}
-
-fn part1(content: &String) {
-    let grid = parse_entries(&content);
-    let starting_point = LightSource::new(usize::MAX, 0, Direction::Right);
+use std::{
+    fmt::{Display, Write},
+    hint::black_box,
+};
+
+use crate::{
+    progress,
+    utils::{frames, Direction4, Grid2D},
+};
+
+pub fn part1(content: &str) -> String {
+    let grid = parse_entries(content);
+    let starting_point = LightSource::new(usize::MAX, 0, Direction4::East);
     let total_energized_entries = process_grid(starting_point, &grid);
-    println!("Count of energized entries: {total_energized_entries}");
+    total_energized_entries.to_string()
 }
 
-fn part2(content: &String) {
-    let grid = parse_entries(&content);
+pub fn part2(content: &str) -> String {
+    let grid = parse_entries(content);
+    let total_starting_points = 2 * (grid.width() + grid.height());
 
     let mut total_counts = vec![];
     for x in 0..grid.width() {
-        let starting_point = LightSource::new(x, usize::MAX, Direction::Down);
+        let starting_point = LightSource::new(x, usize::MAX, Direction4::South);
         let total_energized_entries = process_grid(starting_point, &grid);
         total_counts.push(total_energized_entries);
+        progress::report(total_counts.len(), total_starting_points);
     }
 
     for x in 0..grid.width() {
-        let starting_point = LightSource::new(x, usize::MAX, Direction::Up);
+        let starting_point = LightSource::new(x, usize::MAX, Direction4::North);
         let total_energized_entries = process_grid(starting_point, &grid);
         total_counts.push(total_energized_entries);
+        progress::report(total_counts.len(), total_starting_points);
     }
 
     for y in 0..grid.height() {
-        let starting_point = LightSource::new(usize::MAX, y, Direction::Right);
+        let starting_point = LightSource::new(usize::MAX, y, Direction4::East);
         let total_energized_entries = process_grid(starting_point, &grid);
         total_counts.push(total_energized_entries);
+        progress::report(total_counts.len(), total_starting_points);
     }
 
     for y in 0..grid.height() {
-        let starting_point = LightSource::new(usize::MAX, y, Direction::Left);
+        let starting_point = LightSource::new(usize::MAX, y, Direction4::West);
         let total_energized_entries = process_grid(starting_point, &grid);
         total_counts.push(total_energized_entries);
+        progress::report(total_counts.len(), total_starting_points);
     }
 
     let max_count = total_counts.iter().max().unwrap();
-    println!("Starting point that results in maximum energized entries is: {max_count}");
+    max_count.to_string()
+}
+
+/// Renders the energized tiles from part 1's beam as `#`.
+pub fn grid(content: &str) -> Grid2D<char> {
+    let grid = parse_entries(content);
+    let starting_point = LightSource::new(usize::MAX, 0, Direction4::East);
+    energize(starting_point, &grid).map(|entry| if entry.is_energized { '#' } else { '.' })
 }
 
 fn process_grid(starting_source: LightSource, grid: &Grid2D<Entry>) -> usize {
+    let grid = energize(starting_source, grid);
+    grid.find_all(|entry| entry.is_energized).count()
+}
+
+fn energize(starting_source: LightSource, grid: &Grid2D<Entry>) -> Grid2D<Entry> {
     let mut grid = grid.clone();
     let mut sources = vec![starting_source];
     while sources.len() > 0 {
@@ -68,40 +86,42 @@ fn process_grid(starting_source: LightSource, grid: &Grid2D<Entry>) -> usize {
 
                     match entry.t {
                         EntryType::Mirror(mirror_type) => match mirror_type {
-                            '\\' => match source.dir {
-                                Direction::Left => source.dir = Direction::Up,
-                                Direction::Right => source.dir = Direction::Down,
-                                Direction::Up => source.dir = Direction::Left,
-                                Direction::Down => source.dir = Direction::Right,
-                            },
-                            '/' => match source.dir {
-                                Direction::Left => source.dir = Direction::Down,
-                                Direction::Right => source.dir = Direction::Up,
-                                Direction::Up => source.dir = Direction::Right,
-                                Direction::Down => source.dir = Direction::Left,
-                            },
+                            '\\' => {
+                                source.dir = match source.dir {
+                                    Direction4::East | Direction4::West => source.dir.turn_right(),
+                                    Direction4::North | Direction4::South => source.dir.turn_left(),
+                                }
+                            }
+                            '/' => {
+                                source.dir = match source.dir {
+                                    Direction4::East | Direction4::West => source.dir.turn_left(),
+                                    Direction4::North | Direction4::South => {
+                                        source.dir.turn_right()
+                                    }
+                                }
+                            }
                             _ => {}
                         },
                         EntryType::Splitter(split_type) => {
                             match split_type {
-                                '-' if source.dir == Direction::Up
-                                    || source.dir == Direction::Down =>
+                                '-' if source.dir == Direction4::North
+                                    || source.dir == Direction4::South =>
                                 {
-                                    source.dir = Direction::Left;
+                                    source.dir = Direction4::West;
                                     new_streams.push(LightSource::new(
                                         source.x,
                                         source.y,
-                                        Direction::Right,
+                                        Direction4::East,
                                     ));
                                 }
-                                '|' if source.dir == Direction::Right
-                                    || source.dir == Direction::Left =>
+                                '|' if source.dir == Direction4::East
+                                    || source.dir == Direction4::West =>
                                 {
-                                    source.dir = Direction::Up;
+                                    source.dir = Direction4::North;
                                     new_streams.push(LightSource::new(
                                         source.x,
                                         source.y,
-                                        Direction::Down,
+                                        Direction4::South,
                                     ));
                                 }
                                 _ => {}
@@ -120,45 +140,49 @@ fn process_grid(starting_source: LightSource, grid: &Grid2D<Entry>) -> usize {
         }
 
         sources.extend(new_streams);
+        frames::record(&grid, |entry| match entry.t {
+            _ if entry.is_energized => 2,
+            EntryType::Empty => 0,
+            EntryType::Mirror(_) | EntryType::Splitter(_) => 1,
+        });
     }
 
-    let total_energized_entries = (0..grid.height()).fold(0, |acc, y| {
-        acc + grid.row_iterator(y).filter(|x| x.is_energized).count()
-    });
-
-    return total_energized_entries;
+    grid
 }
 
-fn parse_entries(content: &String) -> Grid2D<Entry> {
-    let rows = content
-        .lines()
-        .map(|l| l.chars().map(|c| Entry::new(c)).collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+/// Reads the contraption, before any beam has gone through it.
+pub fn parse(content: &str) {
+    black_box(parse_entries(content));
+}
 
-    Grid2D::new(rows)
+fn parse_entries(content: &str) -> Grid2D<Entry> {
+    Grid2D::parse(content, |ch, _| {
+        Entry::new(ch).ok_or("expected a mirror, a splitter or empty space")
+    })
+    .unwrap_or_else(|err| panic!("unable to parse contraption: {err}"))
 }
 
 #[derive(Clone)]
 struct Entry {
     t: EntryType,
     is_energized: bool,
-    seen_dirs: Vec<Direction>,
+    seen_dirs: Vec<Direction4>,
 }
 
 impl Entry {
-    fn new(ch: char) -> Self {
+    fn new(ch: char) -> Option<Self> {
         let t = match ch {
             '.' => EntryType::Empty,
             '|' | '-' => EntryType::Splitter(ch),
             '/' | '\\' => EntryType::Mirror(ch),
-            _ => panic!("invalid char received when parsing entry"),
+            _ => return None,
         };
 
-        Self {
+        Some(Self {
             t,
             is_energized: false,
             seen_dirs: vec![],
-        }
+        })
     }
 }
 
@@ -183,11 +207,11 @@ enum EntryType {
 struct LightSource {
     x: usize,
     y: usize,
-    dir: Direction,
+    dir: Direction4,
 }
 
 impl LightSource {
-    fn new(x: usize, y: usize, dir: Direction) -> Self {
+    fn new(x: usize, y: usize, dir: Direction4) -> Self {
         Self { x, y, dir }
     }
 
@@ -197,31 +221,23 @@ impl LightSource {
         }
 
         match self.dir {
-            Direction::Up => self.y > 0,
-            Direction::Down => self.y < max_y,
-            Direction::Left => self.x > 0,
-            Direction::Right => self.x < max_x,
+            Direction4::North => self.y > 0,
+            Direction4::South => self.y < max_y,
+            Direction4::West => self.x > 0,
+            Direction4::East => self.x < max_x,
         }
     }
 
     fn step(&mut self, max_x: usize, max_y: usize) {
         match (self.dir, self.x, self.y) {
-            (Direction::Up, _, usize::MAX) => self.y = max_y - 1,
-            (Direction::Up, _, _) => self.y -= 1,
-            (Direction::Down, _, usize::MAX) => self.y = 0,
-            (Direction::Down, _, _) => self.y += 1,
-            (Direction::Left, usize::MAX, _) => self.x = max_x - 1,
-            (Direction::Left, _, _) => self.x -= 1,
-            (Direction::Right, usize::MAX, _) => self.x = 0,
-            (Direction::Right, _, _) => self.x += 1,
+            (Direction4::North, _, usize::MAX) => self.y = max_y - 1,
+            (Direction4::North, _, _) => self.y -= 1,
+            (Direction4::South, _, usize::MAX) => self.y = 0,
+            (Direction4::South, _, _) => self.y += 1,
+            (Direction4::West, usize::MAX, _) => self.x = max_x - 1,
+            (Direction4::West, _, _) => self.x -= 1,
+            (Direction4::East, usize::MAX, _) => self.x = 0,
+            (Direction4::East, _, _) => self.x += 1,
         }
     }
 }
-
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-}
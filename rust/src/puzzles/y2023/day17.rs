@@ -0,0 +1,87 @@
+use std::hint::black_box;
+
+use crate::utils::{frames, search::dijkstra, Direction4, Grid2D, Point};
+
+const MAX_STRAIGHT_BLOCKS: usize = 3;
+
+pub fn part1(content: &str) -> String {
+    let grid = build_grid(content);
+    let goal = Point::new(grid.width() - 1, grid.height() - 1);
+
+    let start = Crucible {
+        pos: Point::new(0, 0),
+        dir: None,
+        straight_blocks: 0,
+    };
+    // every block the search has expanded so far, for recording how the frontier spreads
+    let recording = frames::is_recording();
+    let mut explored = Grid2D::with_size(grid.width(), grid.height(), 0);
+    let path = dijkstra(
+        start,
+        |crucible| {
+            if recording {
+                explored.replace(2, crucible.pos.x, crucible.pos.y);
+                frames::record(&explored, |&index| index);
+            }
+            crucible.moves(&grid)
+        },
+        |crucible| crucible.pos == goal,
+    )
+    .expect("unable to reach the factory");
+
+    if recording {
+        for crucible in &path.states {
+            explored.replace(3, crucible.pos.x, crucible.pos.y);
+        }
+        frames::push(&explored, |&index| index);
+    }
+
+    path.cost.to_string()
+}
+
+/// Reads the heat loss of every block.
+pub fn parse(content: &str) {
+    black_box(build_grid(content));
+}
+
+fn build_grid(content: &str) -> Grid2D<usize> {
+    Grid2D::parse(content, |ch, _| {
+        ch.to_digit(10)
+            .map(|weight| weight as usize)
+            .ok_or("expected a heat loss digit")
+    })
+    .unwrap_or_else(|err| panic!("unable to parse city map: {err}"))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Crucible {
+    pos: Point,
+    dir: Option<Direction4>,
+    straight_blocks: usize,
+}
+
+impl Crucible {
+    /// Every block the crucible can move to next, along with the heat lost by entering it.
+    fn moves(&self, grid: &Grid2D<usize>) -> Vec<(Self, usize)> {
+        Direction4::ALL
+            .into_iter()
+            .filter(|&dir| self.dir != Some(dir.opposite()))
+            .filter(|&dir| self.dir != Some(dir) || self.straight_blocks < MAX_STRAIGHT_BLOCKS)
+            .filter_map(|dir| {
+                let pos = grid.step(self.pos, dir)?;
+                let straight_blocks = if self.dir == Some(dir) {
+                    self.straight_blocks + 1
+                } else {
+                    1
+                };
+
+                let next = Self {
+                    pos,
+                    dir: Some(dir),
+                    straight_blocks,
+                };
+                Some((next, *grid.get(pos.x, pos.y)?))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,171 @@
+use std::{fmt::Write, hint::black_box};
+
+use crate::utils::{geometry::lattice_points, Direction4, Grid2D, SparseGrid, Vec2};
+
+pub fn part1(content: &str) -> String {
+    solve_internal(content, false)
+}
+
+pub fn part2(content: &str) -> String {
+    solve_internal(content, true)
+}
+
+/// Renders the dug out lagoon from part 1's dig plan.
+pub fn grid(content: &str) -> Grid2D<char> {
+    dig_lagoon(content, false).map(|entry| if entry.filled { '#' } else { '.' })
+}
+
+fn solve_internal(content: &str, from_hex: bool) -> String {
+    // the trench runs through the middle of each cell it digs, so the lagoon covers every
+    // lattice point on or inside the path
+    let corners = trench_corners(&parse_instructions(content, from_hex));
+    lattice_points(&corners).to_string()
+}
+
+/// The corners of the trench, starting from where digging starts.
+fn trench_corners(instructions: &[Instruction]) -> Vec<(i64, i64)> {
+    let mut pos = Vec2::default();
+    let mut corners = vec![];
+    for i in instructions {
+        corners.push((pos.x, pos.y));
+        pos += i.dir.delta() * i.meters as i64;
+    }
+
+    corners
+}
+
+fn dig_lagoon(content: &str, from_hex: bool) -> Grid2D<Entry> {
+    let instructions = parse_instructions(content, from_hex);
+    let mut trench = SparseGrid::new(Entry { filled: false });
+
+    execute_instructions(&mut trench, &instructions);
+    let mut grid = trench.to_grid();
+    fill_grid_area(&mut grid);
+    grid
+}
+
+fn execute_instructions(grid: &mut SparseGrid<Entry>, instructions: &Vec<Instruction>) {
+    let mut pos = (0, 0);
+    for i in instructions {
+        let (cur_x, cur_y) = pos;
+        let meters = i.meters as i64;
+        let delta = i.dir.delta();
+
+        for step in 1..=meters {
+            grid.get_mut(cur_x + delta.x * step, cur_y + delta.y * step)
+                .filled = true;
+        }
+
+        pos = (cur_x + delta.x * meters, cur_y + delta.y * meters);
+    }
+}
+
+fn fill_grid_area(grid: &mut Grid2D<Entry>) {
+    // anything that can't be reached from outside without crossing the trench is in the lagoon
+    let exterior = grid.exterior_fill(|e| !e.filled);
+    for (pos, entry) in grid.iter_mut() {
+        if exterior.get(pos.x, pos.y) == Some(&false) {
+            entry.filled = true;
+        }
+    }
+}
+
+/// Reads part 1's dig plan, which uses the direction letters rather than the colors.
+pub fn parse(content: &str) {
+    black_box(parse_instructions(content, false));
+}
+
+fn parse_instructions(content: &str, from_hex: bool) -> Vec<Instruction> {
+    content
+        .lines()
+        .map(|l| Instruction::new(l, from_hex))
+        .collect::<Vec<_>>()
+}
+
+fn direction_from_char(ch: char) -> Direction4 {
+    match ch {
+        'R' => Direction4::East,
+        'L' => Direction4::West,
+        'U' => Direction4::North,
+        'D' => Direction4::South,
+        _ => panic!("Unable to parse direction"),
+    }
+}
+
+fn direction_from_index(idx: usize) -> Direction4 {
+    match idx {
+        0 => Direction4::East,
+        1 => Direction4::South,
+        2 => Direction4::West,
+        3 => Direction4::North,
+        _ => panic!("unable to parse direction"),
+    }
+}
+
+struct Instruction {
+    dir: Direction4,
+    meters: usize,
+}
+
+impl Instruction {
+    fn new(line: &str, from_hex: bool) -> Self {
+        let parts = line.split(' ').collect::<Vec<_>>();
+        if from_hex {
+            let color = &parts[2].to_string()[2..7];
+            let dir = &parts[2].to_string()[7..8];
+            let meters = usize::from_str_radix(color, 16).unwrap();
+            Self {
+                dir: direction_from_index(dir.parse::<usize>().unwrap()),
+                meters,
+            }
+        } else {
+            Self {
+                dir: direction_from_char(parts[0].chars().nth(0).unwrap()),
+                meters: parts[1].to_string().parse::<usize>().unwrap(),
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Entry {
+    filled: bool,
+}
+
+impl std::fmt::Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(if self.filled { '#' } else { '.' })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_grid_eq, puzzles::y2023::day18::*};
+    use std::fs;
+
+    #[test]
+    fn it_digs_the_trench() {
+        let content = fs::read_to_string("data/2023/test/day18.txt").unwrap();
+        let mut trench = SparseGrid::new(Entry { filled: false });
+        execute_instructions(&mut trench, &parse_instructions(&content, false));
+
+        let expected = [
+            "#######", "#.....#", "###...#", "..#...#", "..#...#", "###.###", "#...#..", "##..###",
+            ".#....#", ".######",
+        ]
+        .join("\n");
+        let expected =
+            Grid2D::parse(&expected, |ch, _| Ok::<_, ()>(Entry { filled: ch == '#' })).unwrap();
+        assert_grid_eq!(expected, trench.to_grid());
+    }
+
+    #[test]
+    fn it_measures_the_lagoon() {
+        let content = fs::read_to_string("data/2023/test/day18.txt").unwrap();
+        assert_eq!("62", part1(&content));
+        assert_eq!("952408144115", part2(&content));
+
+        let lagoon = grid(&content);
+        assert_eq!(62, lagoon.find_all(|&ch| ch == '#').count());
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-pub fn solve(content: String) {
+pub fn part1(content: &str) -> String {
     let mut limits = HashMap::new();
     limits.insert("red", 12);
     limits.insert("green", 13);
@@ -30,7 +30,6 @@ pub fn solve(content: String) {
                 .collect::<Vec<_>>()
                 .iter()
             {
-                println!("{round}");
                 for color in round.split(',') {
                     let color_parts: Vec<&str> = color.trim().split(' ').collect();
                     let num = color_parts
@@ -62,6 +61,5 @@ pub fn solve(content: String) {
         })
         .sum();
 
-    // println!("sum of impossible game IDs {sum}");
-    println!("sum of min powers: {sum}");
+    sum.to_string()
 }
@@ -1,22 +1,13 @@
 use std::collections::HashMap;
 
-pub fn solve(content: String) {
-    let grid = content
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(|l| l.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+use crate::utils::Point;
 
-    part1(&grid);
-    part2(&grid);
-}
-
-fn part1(grid: &Vec<Vec<char>>) {
+pub fn part1(content: &str) -> String {
+    let grid = parse_grid(content);
     let mut sum = 0;
     let mut num = String::new();
     let mut start = usize::max_value();
     for (i, line) in grid.iter().enumerate() {
-        println!("line {i}");
         for (j, char) in line.iter().enumerate() {
             if char.is_digit(10) {
                 if start == usize::max_value() {
@@ -25,7 +16,7 @@ fn part1(grid: &Vec<Vec<char>>) {
 
                 num.push(*char);
             } else if !num.is_empty() {
-                if is_adjacent_to_symbol(i, start, num.len(), grid, None).is_some() {
+                if is_adjacent_to_symbol(i, start, num.len(), &grid, None).is_some() {
                     sum += num.parse::<u32>().expect("unable to parse num");
                 }
                 num.clear();
@@ -34,7 +25,7 @@ fn part1(grid: &Vec<Vec<char>>) {
         }
 
         if !num.is_empty() {
-            if is_adjacent_to_symbol(i, start, num.len(), grid, None).is_some() {
+            if is_adjacent_to_symbol(i, start, num.len(), &grid, None).is_some() {
                 sum += num.parse::<u32>().expect("unable to parse num");
             }
 
@@ -43,13 +34,11 @@ fn part1(grid: &Vec<Vec<char>>) {
         }
     }
 
-    println!("sum of engine parts is {sum}");
+    sum.to_string()
 }
 
-#[derive(Hash, PartialEq, Eq, Debug)]
-struct Point(usize, usize);
-
-fn part2(grid: &Vec<Vec<char>>) {
+pub fn part2(content: &str) -> String {
+    let grid = parse_grid(content);
     let mut gears = HashMap::new();
 
     let mut num = String::new();
@@ -63,7 +52,7 @@ fn part2(grid: &Vec<Vec<char>>) {
 
                 num.push(*char);
             } else if !num.is_empty() {
-                if let Some(point) = is_adjacent_to_symbol(i, start, num.len(), grid, Some(&'*')) {
+                if let Some(point) = is_adjacent_to_symbol(i, start, num.len(), &grid, Some(&'*')) {
                     gears
                         .entry(point)
                         .or_insert(vec![])
@@ -75,7 +64,7 @@ fn part2(grid: &Vec<Vec<char>>) {
         }
 
         if !num.is_empty() {
-            if let Some(point) = is_adjacent_to_symbol(i, start, num.len(), grid, Some(&'*')) {
+            if let Some(point) = is_adjacent_to_symbol(i, start, num.len(), &grid, Some(&'*')) {
                 gears
                     .entry(point)
                     .or_insert(vec![])
@@ -93,7 +82,15 @@ fn part2(grid: &Vec<Vec<char>>) {
         .map(|v| v.iter().copied().reduce(|acc, e| acc * e).unwrap_or(0))
         .sum::<u32>();
 
-    println!("sum of gear ratios is {sum}");
+    sum.to_string()
+}
+
+fn parse_grid(content: &str) -> Vec<Vec<char>> {
+    content
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>()
 }
 
 fn is_adjacent_to_symbol(
@@ -106,12 +103,12 @@ fn is_adjacent_to_symbol(
     let min_col = if column > 0 { column - 1 } else { column };
     let cur_line = grid.get(line).unwrap();
     if is_symbol(min_col, cur_line, symbol) {
-        return Some(Point(min_col, line));
+        return Some(Point::new(min_col, line));
     }
 
     if column + len < cur_line.len() - 1 {
         if is_symbol(column + len, cur_line, symbol) {
-            return Some(Point(column + len, line));
+            return Some(Point::new(column + len, line));
         }
     }
 
@@ -120,7 +117,7 @@ fn is_adjacent_to_symbol(
         if let Some(prev_line) = prev_line {
             for i in min_col..=(prev_line.len() - 1).min(column + len) {
                 if is_symbol(i, prev_line, symbol) {
-                    return Some(Point(i, line - 1));
+                    return Some(Point::new(i, line - 1));
                 }
             }
         }
@@ -130,7 +127,7 @@ fn is_adjacent_to_symbol(
     if let Some(next_line) = next_line {
         for i in min_col..=(next_line.len() - 1).min(column + len) {
             if is_symbol(i, next_line, symbol) {
-                return Some(Point(i, line + 1));
+                return Some(Point::new(i, line + 1));
             }
         }
     }
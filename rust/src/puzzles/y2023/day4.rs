@@ -1,24 +1,13 @@
 use std::collections::HashMap;
 
-pub fn solve(content: String) {
-    let mut cards = content
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| Card::new(line))
-        .collect::<Vec<_>>();
-
-    cards.sort_by(|a, b| a.id.cmp(&b.id));
-
-    part1(&cards);
-    part2(cards);
-}
-
-fn part1(cards: &Vec<Card>) {
+pub fn part1(content: &str) -> String {
+    let cards = parse_cards(content);
     let total_score = cards.iter().map(|card| card.score()).sum::<i64>();
-    println!("total score {total_score}");
+    total_score.to_string()
 }
 
-fn part2(cards: Vec<Card>) {
+pub fn part2(content: &str) -> String {
+    let cards = parse_cards(content);
     let mut accumulation_by_card_id = cards
         .iter()
         .map(|card| (card.id, 1))
@@ -27,10 +16,6 @@ fn part2(cards: Vec<Card>) {
     for card in cards.iter() {
         let match_count = card.match_count() as u32;
         let accumulated = accumulation_by_card_id.get(&card.id).unwrap_or(&0).clone();
-        println!(
-            "card ID: {0}, matches: {match_count}, accumulated: {accumulated}",
-            card.id
-        );
         for offset in 1..=match_count {
             *accumulation_by_card_id.entry(card.id + offset).or_insert(0) += accumulated;
         }
@@ -38,7 +23,18 @@ fn part2(cards: Vec<Card>) {
 
     let winning_card_count = accumulation_by_card_id.into_values().sum::<u64>();
 
-    println!("winning card count: {winning_card_count}");
+    winning_card_count.to_string()
+}
+
+fn parse_cards(content: &str) -> Vec<Card> {
+    let mut cards = content
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(Card::new)
+        .collect::<Vec<_>>();
+
+    cards.sort_by_key(|card| card.id);
+    cards
 }
 
 struct Card {
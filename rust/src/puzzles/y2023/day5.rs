@@ -0,0 +1,207 @@
+use itertools::Itertools;
+use std::{hint::black_box, str::FromStr};
+
+use crate::utils::interval::{Interval, IntervalSet, PiecewiseMap};
+
+pub fn part1(content: &str) -> String {
+    let seed_to_location = seed_to_location(&parse_maps(content));
+    let seeds = parse_seeds(content);
+
+    let min_location = seeds
+        .iter()
+        .map(|&seed| seed_to_location.get(seed))
+        .min()
+        .unwrap_or(usize::MAX);
+
+    min_location.to_string()
+}
+
+pub fn part2(content: &str) -> String {
+    let seed_to_location = seed_to_location(&parse_maps(content));
+    let seeds = parse_seeds(content);
+
+    let seed_ranges = seeds
+        .iter()
+        .enumerate()
+        .group_by(|&(i, _)| i / 2)
+        .into_iter()
+        .map(|(_, group)| {
+            let parts = group.map(|pair| pair.1).collect::<Vec<_>>();
+            if parts.len() != 2 {
+                panic!("Unable to parse seed range");
+            }
+
+            let start = parts[0];
+            let len = parts[1];
+            Interval::with_len(*start, *len)
+        })
+        .collect::<IntervalSet<_>>();
+
+    let min_location = seed_to_location
+        .apply(&seed_ranges)
+        .min()
+        .unwrap_or(usize::MAX);
+
+    min_location.to_string()
+}
+
+/// Composes the maps in the order the almanac follows them, from seeds through to locations.
+fn seed_to_location(maps: &[Map]) -> PiecewiseMap<usize> {
+    let mut map_type = MapType::Seed;
+    let mut seed_to_location = PiecewiseMap::new();
+    while map_type != MapType::Location {
+        let map = maps
+            .iter()
+            .find(|m| m.from == map_type)
+            .unwrap_or_else(|| panic!("Unable to find map of from type {:?}", map_type));
+
+        seed_to_location = seed_to_location.compose(&map.mapping);
+        map_type = map.to.clone();
+    }
+
+    seed_to_location
+}
+
+/// Reads the seeds and maps, which is the same for both parts.
+pub fn parse(content: &str) {
+    black_box((parse_seeds(content), parse_maps(content)));
+}
+
+fn parse_seeds(content: &str) -> Vec<usize> {
+    content
+        .lines()
+        .nth(0)
+        .expect("unable to parse seeds from top of content")
+        .split(' ')
+        .map(|s| usize::from_str(s))
+        .filter(|u| u.is_ok())
+        .map(|u| u.unwrap())
+        .collect::<Vec<_>>()
+}
+
+fn parse_maps(content: &str) -> Vec<Map> {
+    let mut maps = vec![];
+    let mut cur_map: Option<Map> = None;
+    for line in content.lines().skip(1) {
+        if line.is_empty() {
+            if cur_map.is_some() {
+                maps.push(cur_map.unwrap().clone());
+                cur_map = None;
+            }
+        } else if let Some(ref mut map) = cur_map {
+            let parts = line
+                .split(' ')
+                .filter(|s| !s.is_empty())
+                .map(|s| usize::from_str(s))
+                .filter(|u| u.is_ok())
+                .map(|u| u.unwrap())
+                .collect::<Vec<_>>();
+
+            if parts.len() != 3 {
+                panic!("Unable to parse entry from line: {line}");
+            }
+
+            map.mapping
+                .insert(Interval::with_len(parts[1], parts[2]), parts[0]);
+        } else {
+            let map_types = line
+                .split(' ')
+                .nth(0)
+                .unwrap()
+                .split('-')
+                .collect::<Vec<_>>();
+
+            if map_types.len() != 3 {
+                panic!("Unable to parse map types {line}");
+            }
+
+            let from_type = MapType::from_str(map_types[0]).unwrap();
+            let to_type = MapType::from_str(map_types[2]).unwrap();
+
+            cur_map = Some(Map::new(from_type, to_type));
+        }
+    }
+
+    if cur_map.is_some() {
+        maps.push(cur_map.unwrap().clone());
+    }
+
+    return maps;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MapType {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct MapTypeParseErr;
+
+impl FromStr for MapType {
+    type Err = MapTypeParseErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "seed" => Ok(Self::Seed),
+            "soil" => Ok(Self::Soil),
+            "fertilizer" => Ok(Self::Fertilizer),
+            "water" => Ok(Self::Water),
+            "light" => Ok(Self::Light),
+            "temperature" => Ok(Self::Temperature),
+            "humidity" => Ok(Self::Humidity),
+            "location" => Ok(Self::Location),
+            _ => Err(MapTypeParseErr),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Map {
+    from: MapType,
+    to: MapType,
+    mapping: PiecewiseMap<usize>,
+}
+
+impl Map {
+    fn new(from: MapType, to: MapType) -> Self {
+        Map {
+            from,
+            to,
+            mapping: PiecewiseMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzles::y2023::day5::*;
+    use std::fs;
+
+    #[test]
+    fn it_maps_ranges_hanging_off_an_entry() {
+        let mut map = Map::new(MapType::Seed, MapType::Soil);
+        map.mapping.insert(Interval::with_len(10, 5), 20);
+
+        // 5..15 starts before the entry, and 12..22 ends after it
+        let seeds = [Interval::with_len(5, 10), Interval::with_len(12, 10)];
+        let soil = map.mapping.apply(&seeds.into_iter().collect());
+        assert_eq!(
+            &[Interval::new(5, 10), Interval::new(15, 25)],
+            soil.intervals()
+        );
+    }
+
+    #[test]
+    fn it_finds_the_lowest_location() {
+        let content = fs::read_to_string("data/2023/test/day5.txt").unwrap();
+        assert_eq!("35", part1(&content));
+        assert_eq!("46", part2(&content));
+    }
+}
@@ -1,11 +1,5 @@
-pub fn solve(content: String) {
-    let games = parse_games(content.clone());
-
-    part1(&games);
-    part2(content);
-}
-
-fn part1(games: &Vec<Game>) {
+pub fn part1(content: &str) -> String {
+    let games = parse_games(content);
     let winner_mult = games
         .iter()
         .map(|g| g.winning_method_count())
@@ -13,10 +7,10 @@ fn part1(games: &Vec<Game>) {
         .reduce(|acc, x| acc * x)
         .unwrap_or(usize::MAX);
 
-    println!("Part1 | Winning method multiplier: {winner_mult}");
+    winner_mult.to_string()
 }
 
-fn part2(content: String) {
+pub fn part2(content: &str) -> String {
     let mut lines = content.lines();
     let timing = lines
         .next()
@@ -43,10 +37,10 @@ fn part2(content: String) {
     let game = Game::new(timing, record_distance);
     let winning_method_count = game.winning_method_count();
 
-    println!("Part2 | Winning method multiplier: {winning_method_count}");
+    winning_method_count.to_string()
 }
 
-fn parse_games(content: String) -> Vec<Game> {
+fn parse_games(content: &str) -> Vec<Game> {
     let mut lines = content.lines();
     let timings = lines
         .next()
@@ -120,12 +114,13 @@ impl Game {
 
 #[cfg(test)]
 mod tests {
-    use crate::day6::*;
+    use crate::puzzles::y2023::day6::*;
     use std::fs;
 
     #[test]
     fn it_passes_test() {
-        let content = fs::read_to_string("data/test/day6.txt").unwrap();
-        solve(content);
+        let content = fs::read_to_string("data/2023/test/day6.txt").unwrap();
+        assert_eq!("288", part1(&content));
+        assert_eq!("71503", part2(&content));
     }
 }
@@ -2,13 +2,8 @@ use std::cmp::Ordering;
 
 use itertools::Itertools;
 
-pub fn solve(content: String) {
+pub fn part1(content: &str) -> String {
     let hands = parse_hands(content);
-
-    part1(&hands);
-}
-
-fn part1(hands: &Vec<Hand>) {
     let total_score: usize = hands
         .iter()
         .sorted_by(|a, b| a.cmp(&b))
@@ -16,10 +11,10 @@ fn part1(hands: &Vec<Hand>) {
         .map(|(rank, hand)| hand.bid * (rank + 1))
         .sum();
 
-    println!("Total Score: {total_score}");
+    total_score.to_string()
 }
 
-fn parse_hands(content: String) -> Vec<Hand> {
+fn parse_hands(content: &str) -> Vec<Hand> {
     content
         .lines()
         .map(|line| {
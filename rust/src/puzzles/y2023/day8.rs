@@ -1,12 +1,9 @@
 use regex::Regex;
 use std::collections::HashMap;
 
-pub fn solve(content: String) {
-    part1(&content);
-    part2(&content);
-}
+use crate::utils::math::lcm_all;
 
-fn part1(content: &String) {
+pub fn part1(content: &str) -> String {
     let (instructions, map) = parse_instructions_and_map(content);
     let mut key = "AAA";
     let mut steps = 0;
@@ -28,10 +25,10 @@ fn part1(content: &String) {
         }
     }
 
-    println!("Part1 | found our way out in {steps} steps");
+    steps.to_string()
 }
 
-fn part2(content: &String) {
+pub fn part2(content: &str) -> String {
     let (instructions, map) = parse_instructions_and_map(content);
 
     let mut loops = map
@@ -66,60 +63,10 @@ fn part2(content: &String) {
         }
     }
 
-    let mut loop_lengths = loops.iter().map(|(_, len)| *len).collect::<Vec<_>>();
-    let mut prime_multiples = vec![];
-    loop {
-        if loop_lengths.iter().filter(|&l| l != &1).count() == 0 {
-            break;
-        }
-
-        let mut i: usize = 2;
-        loop {
-            if is_prime(i) {
-                let mut did_divide = false;
-                for len in loop_lengths
-                    .iter_mut()
-                    .filter(|v| (**v as f32) % (i as f32) == 0.0)
-                {
-                    *len = len.clone() / i;
-                    did_divide = true;
-                }
-
-                if did_divide {
-                    prime_multiples.push(i.clone());
-                    break;
-                }
-            }
-
-            i += 1;
-        }
-    }
-
-    let lcm = prime_multiples
-        .iter()
-        .copied()
-        .reduce(|acc, x| acc * x)
-        .expect("Unable to fold the LCM");
-
-    println!("Part2 | found our way out in {lcm} steps");
-}
-
-fn is_prime(num: usize) -> bool {
-    if num == 2 {
-        return true;
-    }
-
-    for i in 2..=(f32::sqrt(num as f32) as i32) + 1 {
-        println!("{i}");
-        if (num as f32) % (i as f32) == 0.0 {
-            return false;
-        }
-    }
-
-    return true;
+    lcm_all(loops.iter().map(|&(_, len)| len)).to_string()
 }
 
-fn parse_instructions_and_map(content: &String) -> (String, HashMap<String, (String, String)>) {
+fn parse_instructions_and_map(content: &str) -> (String, HashMap<String, (String, String)>) {
     let mut lines = content.lines();
 
     let instructions = lines.next().unwrap().to_owned();
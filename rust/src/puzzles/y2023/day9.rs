@@ -1,9 +1,4 @@
-pub fn solve(content: String) {
-    part1(content.clone());
-    part2(content.clone());
-}
-
-fn part1(content: String) {
+pub fn part1(content: &str) -> String {
     let total_extrapolated_entries: i64 = content
         .lines()
         .map(|line| {
@@ -22,7 +17,6 @@ fn part1(content: String) {
             let mut current = base.clone();
 
             while current.iter().filter(|&e| e != &0).count() > 0 {
-                println!("{:?}", current);
                 let next = current
                     .iter()
                     .skip(1)
@@ -32,23 +26,21 @@ fn part1(content: String) {
                         cur - prev
                     })
                     .collect::<Vec<_>>();
-                println!("{:?}", next);
 
                 layers.push(next.clone());
                 current = next.clone();
             }
 
             let extrapolated_entry = layers.iter().fold(0, |acc, l| acc + l.last().unwrap());
-            println!("Pattern: {line} | Next entry: {extrapolated_entry}");
 
             return extrapolated_entry;
         })
         .sum();
 
-    println!("Sum of extrapolated entries: {total_extrapolated_entries}");
+    total_extrapolated_entries.to_string()
 }
 
-fn part2(content: String) {
+pub fn part2(content: &str) -> String {
     let total_extrapolated_entries: i64 = content
         .lines()
         .map(|line| {
@@ -67,7 +59,6 @@ fn part2(content: String) {
             let mut current = base.clone();
 
             while current.iter().filter(|&e| e != &0).count() > 0 {
-                println!("{:?}", current);
                 let next = current
                     .iter()
                     .skip(1)
@@ -86,11 +77,10 @@ fn part2(content: String) {
                 .iter()
                 .rev()
                 .fold(0, |acc, l| l.first().unwrap() - acc);
-            println!("Pattern: {line} | Previous entry: {extrapolated_entry}");
 
             return extrapolated_entry;
         })
         .sum();
 
-    println!("Sum of extrapolated entries: {total_extrapolated_entries}");
+    total_extrapolated_entries.to_string()
 }
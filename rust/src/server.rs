@@ -0,0 +1,323 @@
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    panic, thread,
+};
+
+use crate::puzzles::{find_year, Year};
+
+const MAX_BODY_LEN: usize = 16 * 1024 * 1024;
+
+/// Serves the registered solvers over HTTP on the loopback interface.
+///
+/// * `GET /days` lists each day of `year` and its implemented parts
+/// * `GET /days/{year}` does the same for another year
+/// * `POST /solve/{day}/{part}` runs a solver from `year` with the request body as the puzzle input
+/// * `POST /solve/{year}/{day}/{part}` does the same for another year
+pub fn run(port: u16, year: &'static Year) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!("Listening on http://{}", listener.local_addr()?);
+    serve(listener, year)
+}
+
+fn serve(listener: TcpListener, year: &'static Year) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            if let Err(err) = handle_connection(stream, year) {
+                eprintln!("failed to handle connection: {err}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, year: &'static Year) -> io::Result<()> {
+    let response = match read_request(&mut stream) {
+        Ok(request) => route(&request, year),
+        Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+            Response::error(400, &err.to_string())
+        }
+        Err(err) => return Err(err),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn read_request(stream: &mut TcpStream) -> io::Result<Request> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_owned());
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().ok_or_else(|| invalid("missing method"))?;
+    let path = parts.next().ok_or_else(|| invalid("missing path"))?;
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            break;
+        }
+
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| invalid("invalid content-length"))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_LEN {
+        return Err(invalid("request body too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8(body).map_err(|_| invalid("request body is not valid utf-8"))?;
+
+    Ok(Request {
+        method: method.to_owned(),
+        path: path.to_owned(),
+        body,
+    })
+}
+
+fn route(request: &Request, year: &'static Year) -> Response {
+    let segments = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => list_days(year),
+        ("GET", ["days", year]) => match parse_year(year) {
+            Ok(year) => list_days(year),
+            Err(response) => response,
+        },
+        ("POST", ["solve", day, part]) => solve(year, day, part, &request.body),
+        ("POST", ["solve", year, day, part]) => match parse_year(year) {
+            Ok(year) => solve(year, day, part, &request.body),
+            Err(response) => response,
+        },
+        (_, ["days", ..]) | (_, ["solve", ..]) => Response::error(405, "method not allowed"),
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn parse_year(year: &str) -> Result<&'static Year, Response> {
+    year.parse::<u16>()
+        .ok()
+        .and_then(find_year)
+        .ok_or_else(|| Response::error(404, &format!("unknown year '{year}'")))
+}
+
+fn list_days(year: &Year) -> Response {
+    let days = year
+        .days
+        .iter()
+        .map(|day| {
+            let parts = day
+                .parts()
+                .iter()
+                .map(|part| part.to_string())
+                .collect::<Vec<_>>();
+            format!(
+                "{{\"name\":{},\"parts\":[{}]}}",
+                json_string(day.name),
+                parts.join(",")
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Response::ok(format!(
+        "{{\"year\":{},\"days\":[{}]}}",
+        year.year,
+        days.join(",")
+    ))
+}
+
+fn solve(year: &Year, day: &str, part: &str, input: &str) -> Response {
+    let Some(day) = year.find_day(day) else {
+        return Response::error(404, &format!("unknown day '{day}'"));
+    };
+
+    let Ok(part) = part.parse::<u8>() else {
+        return Response::error(400, &format!("invalid part '{part}'"));
+    };
+
+    // solvers panic on malformed input, so keep that from taking down the connection
+    let solution = match panic::catch_unwind(|| day.run(part, input)) {
+        Ok(Some(solution)) => solution,
+        Ok(None) => {
+            return Response::error(404, &format!("{} has no part {part}", day.name));
+        }
+        Err(_) => return Response::error(500, "solver panicked"),
+    };
+
+    Response::ok(format!(
+        "{{\"year\":{},\"day\":{},\"part\":{part},\"answer\":{},\"elapsed_ms\":{:.3}}}",
+        year.year,
+        json_string(day.name),
+        json_string(&solution.answer),
+        solution.elapsed.as_secs_f64() * 1000.0
+    ))
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzles::DEFAULT_YEAR;
+    use crate::server::*;
+    use std::{fs, net::SocketAddr};
+
+    fn start_server() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let year = find_year(DEFAULT_YEAR).unwrap();
+        thread::spawn(move || serve(listener, year));
+        addr
+    }
+
+    fn send(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let status = response
+            .split(' ')
+            .nth(1)
+            .and_then(|s| s.parse::<u16>().ok())
+            .unwrap();
+        let body = response.split("\r\n\r\n").nth(1).unwrap().to_owned();
+        (status, body)
+    }
+
+    #[test]
+    fn it_lists_days() {
+        let addr = start_server();
+        let (status, body) = send(addr, "GET", "/days", "");
+
+        assert_eq!(200, status);
+        assert!(body.starts_with("{\"year\":2023,"));
+        for day in find_year(2023).unwrap().days {
+            assert!(body.contains(&format!("\"name\":\"{}\"", day.name)));
+        }
+        assert!(body.contains("{\"name\":\"day6\",\"parts\":[1,2]}"));
+        assert!(body.contains("{\"name\":\"day7\",\"parts\":[1]}"));
+
+        let (status, explicit) = send(addr, "GET", "/days/2023", "");
+        assert_eq!(200, status);
+        assert_eq!(body, explicit);
+    }
+
+    #[test]
+    fn it_solves_over_loopback() {
+        let addr = start_server();
+        let input = fs::read_to_string("data/2023/test/day6.txt").unwrap();
+
+        let (status, body) = send(addr, "POST", "/solve/day6/1", &input);
+        assert_eq!(200, status);
+        assert!(body.starts_with(
+            "{\"year\":2023,\"day\":\"day6\",\"part\":1,\"answer\":\"288\",\"elapsed_ms\":"
+        ));
+
+        let (status, body) = send(addr, "POST", "/solve/2023/6/2", &input);
+        assert_eq!(200, status);
+        assert!(body.contains("\"answer\":\"71503\""));
+    }
+
+    #[test]
+    fn it_rejects_unknown_routes() {
+        let addr = start_server();
+
+        assert_eq!(404, send(addr, "POST", "/solve/day99/1", "").0);
+        assert_eq!(404, send(addr, "POST", "/solve/1999/day6/1", "").0);
+        assert_eq!(404, send(addr, "GET", "/days/1999", "").0);
+        assert_eq!(404, send(addr, "POST", "/solve/day7/2", "").0);
+        assert_eq!(400, send(addr, "POST", "/solve/day6/x", "").0);
+        assert_eq!(405, send(addr, "GET", "/solve/day6/1", "").0);
+        assert_eq!(404, send(addr, "GET", "/nope", "").0);
+    }
+
+    #[test]
+    fn it_escapes_json_strings() {
+        assert_eq!("\"a\\\"b\\\\c\\n\"", json_string("a\"b\\c\n"));
+    }
+}
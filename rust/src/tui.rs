@@ -0,0 +1,383 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    panic,
+    time::Duration,
+};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::puzzles::{Day, Year};
+
+const DAY_LIST_WIDTH: usize = 8;
+const RESULTS_WIDTH: usize = 32;
+const HORIZONTAL_STEP: usize = 8;
+
+/// Runs the interactive browser until the user quits.
+///
+/// Days are listed on the left, the selected input (or grid) in the middle and the answers for
+/// the selected day on the right.
+pub fn run(year: &'static Year) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    // solver panics are shown as failed results, so keep their messages off the screen until
+    // the terminal is handed back
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let result = event_loop(&mut stdout, year);
+
+    panic::set_hook(hook);
+    execute!(stdout, Show, LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn event_loop(stdout: &mut io::Stdout, year: &'static Year) -> io::Result<()> {
+    let mut app = App::new(year);
+    loop {
+        let (width, height) = terminal::size()?;
+        app.draw(stdout, width as usize, height as usize)?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && app.handle_key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum DataDir {
+    Actual,
+    Test,
+}
+
+impl DataDir {
+    fn name(&self) -> &'static str {
+        match self {
+            DataDir::Actual => "actual",
+            DataDir::Test => "test",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum View {
+    Input,
+    Grid,
+}
+
+enum Outcome {
+    Solved { answer: String, elapsed: Duration },
+    Failed(String),
+}
+
+struct App {
+    year: &'static Year,
+    selected: usize,
+    data_dir: DataDir,
+    view: View,
+    scroll: usize,
+    offset: usize,
+    input: Option<String>,
+    lines: Vec<String>,
+    results: HashMap<(&'static str, DataDir, u8), Outcome>,
+    status: String,
+}
+
+impl App {
+    fn new(year: &'static Year) -> Self {
+        let mut app = Self {
+            year,
+            selected: 0,
+            data_dir: DataDir::Actual,
+            view: View::Input,
+            scroll: 0,
+            offset: 0,
+            input: None,
+            lines: vec![],
+            results: HashMap::new(),
+            status: String::new(),
+        };
+        app.reload();
+        app
+    }
+
+    fn day(&self) -> &'static Day {
+        &self.year.days[self.selected]
+    }
+
+    fn input_path(&self) -> String {
+        self.year.input_path(self.data_dir.name(), self.day())
+    }
+
+    /// Reloads the input for the selected day and rebuilds the viewer contents.
+    fn reload(&mut self) {
+        self.scroll = 0;
+        self.offset = 0;
+        self.input = fs::read_to_string(self.input_path()).ok();
+
+        self.lines = match (&self.input, self.view) {
+            (None, _) => vec![format!("no input at {}", self.input_path())],
+            (Some(input), View::Input) => input.lines().map(str::to_owned).collect(),
+            (Some(input), View::Grid) => match self.day().grid {
+                Some(render) => match run_quietly(|| render(input)) {
+                    Ok(grid) => grid.to_string().lines().map(str::to_owned).collect(),
+                    Err(err) => vec![format!("unable to render grid: {err}")],
+                },
+                None => vec![format!("{} has no grid to render", self.day().name)],
+            },
+        };
+    }
+
+    fn run_part(&mut self, part: u8) {
+        let day = self.day();
+        let Some(input) = &self.input else {
+            self.status = format!("no input at {}", self.input_path());
+            return;
+        };
+
+        let outcome = match run_quietly(|| day.run(part, input)) {
+            Ok(Some(solution)) => Outcome::Solved {
+                answer: solution.answer,
+                elapsed: solution.elapsed,
+            },
+            Ok(None) => {
+                self.status = format!("{} has no part {part}", day.name);
+                return;
+            }
+            Err(err) => Outcome::Failed(err),
+        };
+
+        self.status = format!("ran {} part {part}", day.name);
+        self.results
+            .insert((day.name, self.data_dir, part), outcome);
+    }
+
+    /// Applies a key press, returning `true` once the user has asked to quit.
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return true,
+            KeyCode::Up if self.selected > 0 => {
+                self.selected -= 1;
+                self.reload();
+            }
+            KeyCode::Down if self.selected < self.year.days.len() - 1 => {
+                self.selected += 1;
+                self.reload();
+            }
+            KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Char('j') => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(self.page()),
+            KeyCode::PageDown => self.scroll_by(self.page()),
+            KeyCode::Home => self.scroll = 0,
+            KeyCode::End => self.scroll = self.lines.len().saturating_sub(1),
+            KeyCode::Left => self.offset = self.offset.saturating_sub(HORIZONTAL_STEP),
+            KeyCode::Right => self.offset += HORIZONTAL_STEP,
+            KeyCode::Char('1') => self.run_part(1),
+            KeyCode::Char('2') => self.run_part(2),
+            KeyCode::Char('t') => {
+                self.data_dir = match self.data_dir {
+                    DataDir::Actual => DataDir::Test,
+                    DataDir::Test => DataDir::Actual,
+                };
+                self.reload();
+            }
+            KeyCode::Char('g') => {
+                self.view = match self.view {
+                    View::Input => View::Grid,
+                    View::Grid => View::Input,
+                };
+                self.reload();
+            }
+            _ => {}
+        }
+
+        false
+    }
+
+    fn page(&self) -> usize {
+        terminal::size()
+            .map(|(_, height)| (height as usize).saturating_sub(3))
+            .unwrap_or(20)
+            .max(1)
+    }
+
+    fn scroll_by(&mut self, amount: usize) {
+        self.scroll = (self.scroll + amount).min(self.lines.len().saturating_sub(1));
+    }
+
+    fn result_lines(&self) -> Vec<String> {
+        let day = self.day();
+        let mut lines = vec![];
+        for part in day.parts() {
+            lines.push(format!("Part {part}"));
+            match self.results.get(&(day.name, self.data_dir, part)) {
+                Some(Outcome::Solved { answer, elapsed }) => {
+                    lines.push(format!("  {answer}"));
+                    lines.push(format!("  in {elapsed:?}"));
+                }
+                Some(Outcome::Failed(err)) => lines.push(format!("  failed: {err}")),
+                None => lines.push(format!("  press {part} to run")),
+            }
+            lines.push(String::new());
+        }
+
+        lines
+    }
+
+    fn draw(&self, out: &mut impl Write, width: usize, height: usize) -> io::Result<()> {
+        queue!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+        if height < 3 {
+            return out.flush();
+        }
+
+        let view = match self.view {
+            View::Input => "input",
+            View::Grid => "grid",
+        };
+        let header = format!(
+            " {} {} | data: {} | view: {} | line {}/{}",
+            self.year.year,
+            self.day().name,
+            self.data_dir.name(),
+            view,
+            (self.scroll + 1).min(self.lines.len()),
+            self.lines.len()
+        );
+        queue!(
+            out,
+            SetAttribute(Attribute::Reverse),
+            Print(fit(&header, width)),
+            SetAttribute(Attribute::Reset)
+        )?;
+
+        let viewer_width = width.saturating_sub(DAY_LIST_WIDTH + RESULTS_WIDTH + 2);
+        let results = self.result_lines();
+        // keep the selected day visible when the terminal is shorter than the day list
+        let first_day = self.selected.saturating_sub(height - 3);
+        for row in 0..height - 2 {
+            queue!(out, MoveTo(0, row as u16 + 1))?;
+
+            let day = self
+                .year
+                .days
+                .get(first_day + row)
+                .map(|day| day.name)
+                .unwrap_or("");
+            if first_day + row == self.selected {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            queue!(
+                out,
+                Print(fit(day, DAY_LIST_WIDTH)),
+                SetAttribute(Attribute::Reset),
+                Print('│')
+            )?;
+
+            let line = self
+                .lines
+                .get(self.scroll + row)
+                .map(|line| line.chars().skip(self.offset).collect::<String>())
+                .unwrap_or_default();
+            let result = results.get(row).map(String::as_str).unwrap_or("");
+            queue!(
+                out,
+                Print(fit(&line, viewer_width)),
+                Print('│'),
+                Print(fit(result, RESULTS_WIDTH))
+            )?;
+        }
+
+        let footer = format!(
+            " ↑↓ day  j/k PgUp/PgDn ←→ scroll  1/2 run  g grid  t test/actual  q quit  {}",
+            self.status
+        );
+        queue!(
+            out,
+            MoveTo(0, height as u16 - 1),
+            SetAttribute(Attribute::Reverse),
+            Print(fit(&footer, width)),
+            SetAttribute(Attribute::Reset)
+        )?;
+
+        out.flush()
+    }
+}
+
+/// Truncates or pads `text` so that it fills exactly `width` columns.
+fn fit(text: &str, width: usize) -> String {
+    let mut fitted = text.chars().take(width).collect::<String>();
+    let len = fitted.chars().count();
+    fitted.push_str(&" ".repeat(width - len));
+    fitted
+}
+
+/// Runs `f`, turning a panic into an error message. The hook installed by [`run`] keeps the
+/// panic itself from being written over the screen.
+fn run_quietly<T>(f: impl FnOnce() -> T + panic::UnwindSafe) -> Result<T, String> {
+    panic::catch_unwind(f).map_err(|err| {
+        err.downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| err.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "solver panicked".to_owned())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzles::{find_year, DEFAULT_YEAR};
+    use crate::tui::*;
+
+    fn select(app: &mut App, name: &str) {
+        while app.day().name != name {
+            app.handle_key(KeyCode::Down);
+        }
+    }
+
+    #[test]
+    fn it_fits_text_to_width() {
+        assert_eq!("ab  ", fit("ab", 4));
+        assert_eq!("abc", fit("abcdef", 3));
+    }
+
+    #[test]
+    fn it_runs_parts_on_keypress() {
+        let mut app = App::new(find_year(DEFAULT_YEAR).unwrap());
+        app.handle_key(KeyCode::Char('t'));
+        select(&mut app, "day6");
+        assert_eq!("Time:      7  15   30", app.lines[0]);
+
+        app.handle_key(KeyCode::Char('1'));
+        app.handle_key(KeyCode::Char('2'));
+        assert!(matches!(
+            app.results.get(&("day6", DataDir::Test, 1)),
+            Some(Outcome::Solved { answer, .. }) if answer == "288"
+        ));
+        assert!(matches!(
+            app.results.get(&("day6", DataDir::Test, 2)),
+            Some(Outcome::Solved { answer, .. }) if answer == "71503"
+        ));
+    }
+
+    #[test]
+    fn it_renders_grid_days() {
+        let mut app = App::new(find_year(DEFAULT_YEAR).unwrap());
+        app.handle_key(KeyCode::Char('t'));
+        select(&mut app, "day16");
+        app.handle_key(KeyCode::Char('g'));
+
+        assert_eq!(View::Grid, app.view);
+        assert_eq!("######....", app.lines[0]);
+        assert!(app.handle_key(KeyCode::Char('q')));
+    }
+}
@@ -1,32 +1,83 @@
 use std::{
-    fmt::{Display, Write},
+    collections::hash_map::DefaultHasher,
+    fmt::{Debug, Display, Write},
+    hash::{Hash, Hasher},
     str::Lines,
 };
 
 use itertools::Itertools;
 
+use addressing::Addressing;
+
+pub mod addressing;
+mod bits;
+pub mod cycle;
+pub mod diff;
+mod direction;
+pub mod fill;
+pub mod frames;
+pub mod geometry;
+pub mod grid3d;
+pub mod image;
+pub mod interval;
+mod iter;
+pub mod math;
+mod point;
+pub mod render;
+pub mod scoped;
+pub mod search;
+mod sparse;
+mod view;
+
+pub use bits::*;
+pub use direction::*;
+pub use point::*;
+pub use sparse::*;
+pub use view::*;
+
+/// A rectangular grid stored row-major in a single contiguous `Vec`.
+///
+/// The cell at `(x, y)` lives at `y * stride + x`. `stride` is always `width` for an owned grid,
+/// but is kept separate so that row offsets are computed in one place. Grids compare and hash by
+/// their size and cells.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid2D<T> {
     height: usize,
     width: usize,
-    rows: Vec<Vec<T>>,
+    stride: usize,
+    cells: Vec<T>,
 }
 
 impl Grid2D<char> {
+    /// Builds a grid of the characters in `lines`, panicking if they aren't all the same length
+    /// since a grid's rows all share one width. [`Grid2D::parse`] reports ragged input as a
+    /// [`GridParseError`] instead.
     pub fn from_lines(lines: Lines) -> Self {
-        let vec = lines
-            .map(|l| l.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for line in lines {
+            cells.extend(line.chars());
+            height += 1;
+
+            let row_width = *width.get_or_insert(cells.len());
+            if cells.len() != row_width * height {
+                panic!("unable to build grid from lines of differing lengths");
+            }
+        }
+
+        let width = width.unwrap_or(0);
         Self {
-            height: vec.len(),
-            width: vec.first().unwrap_or(&Vec::new()).len(),
-            rows: vec,
+            height,
+            width,
+            stride: width,
+            cells,
         }
     }
 
     pub fn to_string(&self) -> String {
-        self.rows
-            .iter()
-            .map(|row| row.iter().collect::<String>())
+        (0..self.height)
+            .map(|y| self.row(y).iter().collect::<String>())
             .join("\n")
     }
 }
@@ -36,8 +87,8 @@ where
     T: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in self.rows.iter() {
-            for item in row.iter() {
+        for y in 0..self.height {
+            for item in self.row(y) {
                 f.write_fmt(format_args!("{item}"))?;
             }
 
@@ -48,12 +99,154 @@ where
     }
 }
 
+/// Why [`Grid2D::parse`] rejected its input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridParseError<E> {
+    /// Line `line` (counting from 0) has a different length to the first line.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The cell parser rejected `ch` at `pos`.
+    Cell { pos: Point, ch: char, error: E },
+}
+
+impl<E> Display for GridParseError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ragged {
+                line,
+                expected,
+                found,
+            } => f.write_fmt(format_args!(
+                "line {} is {found} characters long, expected {expected}",
+                line + 1
+            )),
+            Self::Cell { pos, ch, error } => f.write_fmt(format_args!(
+                "invalid character {ch:?} at line {}, column {}: {error}",
+                pos.y + 1,
+                pos.x + 1
+            )),
+        }
+    }
+}
+
+impl<E> std::error::Error for GridParseError<E> where E: std::fmt::Debug + Display {}
+
+/// Draws a grid one character per cell, as returned by [`Grid2D::display_with`].
+pub struct DisplayWith<'a, T, F> {
+    grid: &'a Grid2D<T>,
+    to_char: F,
+}
+
+impl<T, F> Display for DisplayWith<'_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.grid.height {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+
+            for item in self.grid.row(y) {
+                f.write_char((self.to_char)(item))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Debug for Grid2D<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries((0..self.height).map(|y| self.row(y)))
+            .finish()
+    }
+}
+
 impl<T> Grid2D<T> {
+    /// Builds a grid from its rows, panicking if they aren't all the same length.
     pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            panic!("unable to build grid from rows of differing lengths");
+        }
+
+        let mut cells = Vec::with_capacity(width * height);
+        for row in rows {
+            cells.extend(row);
+        }
+
         Self {
-            height: rows.len(),
-            width: rows.first().unwrap_or(&vec![]).len(),
-            rows,
+            height,
+            width,
+            stride: width,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character of `text`, passing each character and its position to
+    /// `parse_cell`.
+    ///
+    /// Every line must be the same length, although a trailing blank line is ignored.
+    pub fn parse<E>(
+        text: &str,
+        mut parse_cell: impl FnMut(char, Point) -> Result<T, E>,
+    ) -> Result<Self, GridParseError<E>> {
+        let mut lines = text.lines().collect::<Vec<_>>();
+        if lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        let height = lines.len();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, line) in lines.into_iter().enumerate() {
+            let len = line.chars().count();
+            if len != width {
+                return Err(GridParseError::Ragged {
+                    line: y,
+                    expected: width,
+                    found: len,
+                });
+            }
+
+            for (x, ch) in line.chars().enumerate() {
+                let pos = Point::new(x, y);
+                let cell =
+                    parse_cell(ch, pos).map_err(|error| GridParseError::Cell { pos, ch, error })?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self {
+            height,
+            width,
+            stride: width,
+            cells,
+        })
+    }
+
+    /// Displays the grid as text, one line per row, using `to_char` to draw each cell.
+    ///
+    /// This is the inverse of [`Grid2D::parse`] when `to_char` undoes the cell parser.
+    pub fn display_with<F>(&self, to_char: F) -> DisplayWith<'_, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        DisplayWith {
+            grid: self,
+            to_char,
         }
     }
 
@@ -65,26 +258,82 @@ impl<T> Grid2D<T> {
         self.height
     }
 
-    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        if x > self.width || y > self.height {
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        if x >= self.width || y >= self.height {
             return None;
         }
 
-        Some(self.rows.get(y)?.get(x)?)
+        Some(y * self.stride + x)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        let idx = self.index(x, y)?;
+        self.cells.get(idx)
     }
 
     pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
-        Some(self.rows.get_mut(y)?.get_mut(x)?)
+        let idx = self.index(x, y)?;
+        self.cells.get_mut(idx)
     }
 
     pub fn replace(&mut self, item: T, x: usize, y: usize) {
-        if x > self.width || y > self.height {
-            return;
+        if let Some(entry) = self.get_mut(x, y) {
+            *entry = item;
         }
+    }
 
-        let row = self.rows.get_mut(y).unwrap();
-        let x = row.get_mut(x).unwrap();
-        *x = item;
+    /// Returns row `y` as a slice, panicking if it's out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is out of bounds");
+        let start = y * self.stride;
+        &self.cells[start..start + self.width]
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    /// Moves one step from `point` towards `dir`, returning `None` if that leaves the grid.
+    ///
+    /// See [`Grid2D::step_with`] for grids that wrap around or clamp at their edges.
+    pub fn step(&self, point: Point, dir: impl Into<Vec2>) -> Option<Point> {
+        self.step_with(point, dir, Addressing::Bounded)
+    }
+
+    /// The orthogonal neighbors of `point` that lie within the grid, clockwise from north.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors4_with(point, Addressing::Bounded)
+    }
+
+    /// The orthogonal and diagonal neighbors of `point` that lie within the grid, clockwise from
+    /// north.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors8_with(point, Addressing::Bounded)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid2D<U> {
+        Grid2D {
+            height: self.height,
+            width: self.width,
+            stride: self.stride,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Grid2D<T>
+where
+    T: Hash,
+{
+    /// A 64-bit hash of the grid's size and cells, for keying on grid state without storing whole
+    /// grids.
+    ///
+    /// Different grids can share a fingerprint, although with 64 bits that's vanishingly unlikely.
+    /// It's only stable within a single run of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
     }
 }
 
@@ -96,80 +345,209 @@ where
         Self {
             height,
             width,
-            rows: vec![vec![default; width]; height],
+            stride: width,
+            cells: vec![default; width * height],
         }
     }
 }
 
-impl<'b, T> Grid2D<T> {
-    pub fn row_iterator<'a>(&self, row_index: usize) -> RowIterator<T> {
+impl<T> Grid2D<T> {
+    /// Iterates over row `row_index`, yielding nothing if it's out of bounds.
+    pub fn row_iterator(&self, row_index: usize) -> RowIterator<'_, T> {
+        let cells = if row_index < self.height {
+            self.row(row_index)
+        } else {
+            &[]
+        };
+
         RowIterator {
-            grid: self,
-            row_index,
-            index: 0,
+            cells: cells.iter(),
         }
     }
 
-    pub fn col_iterator<'a>(&self, column_index: usize) -> ColumnIterator<T> {
-        ColumnIterator {
-            grid: self,
-            column_index,
-            index: 0,
-        }
-    }
-}
+    /// Iterates down column `column_index`, yielding nothing if it's out of bounds.
+    pub fn col_iterator(&self, column_index: usize) -> ColumnIterator<'_, T> {
+        let remaining = if column_index < self.width {
+            self.height
+        } else {
+            0
+        };
 
-impl<T> Clone for Grid2D<T>
-where
-    T: Clone,
-{
-    fn clone(&self) -> Self {
-        Self {
-            width: self.width,
-            height: self.height,
-            rows: self.rows.clone(),
+        ColumnIterator {
+            cells: &self.cells,
+            stride: self.stride,
+            index: column_index,
+            remaining,
         }
     }
 }
 
 pub struct ColumnIterator<'a, T> {
-    grid: &'a Grid2D<T>,
-    column_index: usize,
+    cells: &'a [T],
+    stride: usize,
     index: usize,
+    remaining: usize,
 }
 
 impl<'a, T> Iterator for ColumnIterator<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index > self.grid.height {
+        if self.remaining == 0 {
             return None;
         }
 
-        self.index += 1;
-
-        let row = self.grid.rows.get(self.index - 1)?;
-        Some(row.get(self.column_index)?)
+        let item = &self.cells[self.index];
+        self.index += self.stride;
+        self.remaining -= 1;
+        Some(item)
     }
 }
 
 pub struct RowIterator<'a, T> {
-    grid: &'a Grid2D<T>,
-    row_index: usize,
-    index: usize,
+    cells: std::slice::Iter<'a, T>,
 }
 
 impl<'a, T> Iterator for RowIterator<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index > self.grid.width {
-            return None;
-        }
+        self.cells.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+
+    fn grid() -> Grid2D<char> {
+        Grid2D::from_lines("abc\ndef".lines())
+    }
+
+    #[test]
+    fn it_stores_rows_contiguously() {
+        let grid = grid();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!(Some(&'f'), grid.get(2, 1));
+        assert_eq!("abc\ndef", grid.to_string());
+    }
+
+    #[test]
+    fn it_rejects_out_of_bounds_access() {
+        let mut grid = grid();
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get(0, 2));
+
+        grid.replace('z', 3, 0);
+        assert_eq!("abc\ndef", grid.to_string());
+    }
+
+    #[test]
+    fn it_iterates_rows_and_columns() {
+        let grid = grid();
+        assert_eq!("def", grid.row_iterator(1).collect::<String>());
+        assert_eq!("cf", grid.col_iterator(2).collect::<String>());
+        assert_eq!(0, grid.row_iterator(2).count());
+        assert_eq!(0, grid.col_iterator(3).count());
+    }
+
+    #[test]
+    fn it_finds_neighbors_within_bounds() {
+        let grid = grid();
+        assert_eq!(
+            Some(Point::new(1, 1)),
+            grid.step(Point::new(1, 0), Direction4::South)
+        );
+        assert_eq!(None, grid.step(Point::new(1, 0), Direction4::North));
+        assert_eq!(
+            vec![Point::new(1, 0), Point::new(0, 1)],
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbors8(Point::new(1, 1)).count());
+    }
+
+    #[test]
+    fn it_parses_cells_and_displays_them_again() {
+        let text = "123\n456\n";
+        let grid = Grid2D::parse(text, |ch, _| ch.to_digit(10).ok_or("not a digit")).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&6), grid.get(2, 1));
+
+        let shown = grid.display_with(|&n| char::from_digit(n, 10).unwrap());
+        assert_eq!(text.trim_end(), shown.to_string());
+    }
 
-        self.index += 1;
+    #[test]
+    fn it_skips_a_trailing_blank_line() {
+        let digit = |ch: char, _| ch.to_digit(10).ok_or("not a digit");
+        let grid = Grid2D::parse("12\n34\n\n", digit).unwrap();
+        assert_eq!((2, 2), (grid.width(), grid.height()));
+        assert_eq!(Grid2D::parse("12\n34", digit), Ok(grid));
+
+        // only the one, so any more blank lines are rows of the wrong length
+        assert_eq!(
+            Err(GridParseError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 0
+            }),
+            Grid2D::parse("12\n34\n\n\n", digit).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn it_reports_where_parsing_failed() {
+        let digit = |ch: char, _| ch.to_digit(10).ok_or("not a digit");
+        assert_eq!(
+            Err(GridParseError::Cell {
+                pos: Point::new(1, 1),
+                ch: 'x',
+                error: "not a digit"
+            }),
+            Grid2D::parse("12\n3x", digit).map(|_| ())
+        );
+        assert_eq!(
+            Err(GridParseError::Ragged {
+                line: 1,
+                expected: 2,
+                found: 1
+            }),
+            Grid2D::parse("12\n3", digit).map(|_| ())
+        );
+        // a ragged row further down is reported by its own line number, counting from 1
+        assert_eq!(
+            "line 3 is 3 characters long, expected 2",
+            Grid2D::parse("12\n34\n567\n89", digit)
+                .err()
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid character 'x' at line 2, column 2: not a digit",
+            Grid2D::parse("12\n3x", digit).err().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn it_compares_and_fingerprints_grids() {
+        let mut other = grid();
+        assert_eq!(grid(), other);
+        assert_eq!(grid().fingerprint(), other.fingerprint());
+
+        other.replace('z', 0, 0);
+        assert_ne!(grid(), other);
+        assert_ne!(grid().fingerprint(), other.fingerprint());
+
+        // the same cells in a different shape make a different grid
+        let reshaped = Grid2D::from_lines("ab\ncd\nef".lines());
+        assert_ne!(grid(), reshaped);
+        assert_ne!(grid().fingerprint(), reshaped.fingerprint());
+    }
 
-        let row = self.grid.rows.get(self.row_index)?;
-        Some(row.get(self.index - 1)?)
+    #[test]
+    #[should_panic]
+    fn it_rejects_ragged_rows() {
+        Grid2D::new(vec![vec![1, 2], vec![3]]);
     }
 }
@@ -0,0 +1,206 @@
+use super::{Direction4, Direction8, Grid2D, Point, Vec2};
+
+/// How positions that fall outside a grid are treated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Addressing {
+    /// Positions outside the grid don't exist.
+    #[default]
+    Bounded,
+    /// The grid repeats forever in every direction, so leaving one edge comes back in at the
+    /// opposite one.
+    Wrapping,
+    /// Positions outside the grid are pulled back to the nearest edge.
+    Clamped,
+}
+
+impl Addressing {
+    /// Maps `(x, y)` onto a grid of the given size, returning `None` if it's outside a bounded
+    /// grid or the grid is empty.
+    #[inline]
+    pub fn resolve(self, x: i64, y: i64, width: usize, height: usize) -> Option<Point> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let (width, height) = (width as i64, height as i64);
+        let (x, y) = match self {
+            Addressing::Bounded => {
+                if !(0..width).contains(&x) || !(0..height).contains(&y) {
+                    return None;
+                }
+                (x, y)
+            }
+            Addressing::Wrapping => (x.rem_euclid(width), y.rem_euclid(height)),
+            Addressing::Clamped => (x.clamp(0, width - 1), y.clamp(0, height - 1)),
+        };
+
+        Some(Point::new(x as usize, y as usize))
+    }
+}
+
+/// A grid read through an [`Addressing`] mode, as returned by [`Grid2D::addressed`].
+pub struct Addressed<'a, T> {
+    grid: &'a Grid2D<T>,
+    mode: Addressing,
+}
+
+impl<T> Clone for Addressed<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Addressed<'_, T> {}
+
+impl<T> Grid2D<T> {
+    /// Reads the grid with positions outside it handled by `mode`.
+    pub fn addressed(&self, mode: Addressing) -> Addressed<'_, T> {
+        Addressed { grid: self, mode }
+    }
+
+    /// Looks up `(x, y)`, with positions outside the grid handled by `mode`.
+    pub fn get_with(&self, x: i64, y: i64, mode: Addressing) -> Option<&T> {
+        let pos = mode.resolve(x, y, self.width, self.height)?;
+        self.get(pos.x, pos.y)
+    }
+
+    /// Moves one step from `point` towards `dir`, with steps that leave the grid handled by
+    /// `mode`.
+    #[inline]
+    pub fn step_with(&self, point: Point, dir: impl Into<Vec2>, mode: Addressing) -> Option<Point> {
+        let delta = dir.into();
+        mode.resolve(
+            point.x as i64 + delta.x,
+            point.y as i64 + delta.y,
+            self.width,
+            self.height,
+        )
+    }
+
+    /// The orthogonal neighbors of `point` under `mode`, clockwise from north.
+    ///
+    /// A step that comes straight back to `point`, such as clamping at an edge, isn't a neighbor,
+    /// and a cell reached in more than one direction, such as wrapping on a grid two cells wide,
+    /// is only listed the first time.
+    pub fn neighbors4_with(
+        &self,
+        point: Point,
+        mode: Addressing,
+    ) -> impl Iterator<Item = Point> + '_ {
+        self.distinct_steps(point, Direction4::ALL, mode)
+    }
+
+    /// The orthogonal and diagonal neighbors of `point` under `mode`, clockwise from north, with
+    /// the same exclusions as [`Grid2D::neighbors4_with`].
+    pub fn neighbors8_with(
+        &self,
+        point: Point,
+        mode: Addressing,
+    ) -> impl Iterator<Item = Point> + '_ {
+        self.distinct_steps(point, Direction8::ALL, mode)
+    }
+
+    /// The cells one step from `point` in each of `dirs`, leaving out `point` itself and any
+    /// repeats. They're gathered on the stack since this sits in the middle of most searches.
+    fn distinct_steps<D: Into<Vec2>, const N: usize>(
+        &self,
+        point: Point,
+        dirs: [D; N],
+        mode: Addressing,
+    ) -> impl Iterator<Item = Point> {
+        let mut neighbors = [point; N];
+        let mut len = 0;
+        for dir in dirs {
+            if let Some(next) = self.step_with(point, dir, mode) {
+                if next != point && !neighbors[..len].contains(&next) {
+                    neighbors[len] = next;
+                    len += 1;
+                }
+            }
+        }
+
+        neighbors.into_iter().take(len)
+    }
+}
+
+impl<'a, T> Addressed<'a, T> {
+    pub fn grid(&self) -> &'a Grid2D<T> {
+        self.grid
+    }
+
+    pub fn mode(&self) -> Addressing {
+        self.mode
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&'a T> {
+        self.grid.get_with(x, y, self.mode)
+    }
+
+    pub fn step(&self, point: Point, dir: impl Into<Vec2>) -> Option<Point> {
+        self.grid.step_with(point, dir, self.mode)
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + 'a {
+        self.grid.neighbors4_with(point, self.mode)
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + 'a {
+        self.grid.neighbors8_with(point, self.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{addressing::*, *};
+
+    fn grid() -> Grid2D<char> {
+        Grid2D::from_lines("abc\ndef".lines())
+    }
+
+    #[test]
+    fn it_resolves_positions_outside_the_grid() {
+        let grid = grid();
+        assert_eq!(None, grid.get_with(3, 0, Addressing::Bounded));
+        assert_eq!(Some(&'e'), grid.get_with(1, 1, Addressing::Bounded));
+        assert_eq!(Some(&'a'), grid.get_with(3, 0, Addressing::Wrapping));
+        assert_eq!(Some(&'f'), grid.get_with(-1, -3, Addressing::Wrapping));
+        assert_eq!(Some(&'f'), grid.get_with(10, 10, Addressing::Clamped));
+        assert_eq!(Some(&'a'), grid.addressed(Addressing::Clamped).get(-5, 0));
+
+        let empty = Grid2D::<char>::new(vec![]);
+        assert_eq!(None, empty.get_with(0, 0, Addressing::Wrapping));
+    }
+
+    #[test]
+    fn it_finds_neighbors_under_each_mode() {
+        let grid = grid();
+        let corner = Point::new(0, 0);
+        let neighbors = |mode| grid.neighbors4_with(corner, mode).collect::<Vec<_>>();
+        assert_eq!(
+            grid.neighbors4(corner).collect::<Vec<_>>(),
+            neighbors(Addressing::Bounded)
+        );
+        assert_eq!(
+            vec![Point::new(0, 1), Point::new(1, 0), Point::new(2, 0)],
+            neighbors(Addressing::Wrapping)
+        );
+        assert_eq!(
+            vec![Point::new(1, 0), Point::new(0, 1)],
+            neighbors(Addressing::Clamped)
+        );
+        // every other cell of the 3x2 grid is one step away once it wraps
+        assert_eq!(
+            5,
+            grid.neighbors8_with(corner, Addressing::Wrapping).count()
+        );
+        let single = Grid2D::from_lines("a".lines());
+        assert_eq!(
+            0,
+            single.neighbors8_with(corner, Addressing::Wrapping).count()
+        );
+        assert_eq!(
+            Some(Point::new(2, 1)),
+            grid.step_with(corner, Direction8::NorthWest, Addressing::Wrapping)
+        );
+    }
+}
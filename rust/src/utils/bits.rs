@@ -0,0 +1,269 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+
+use super::Grid2D;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of on/off cells packed 64 to a word, with each row starting on a fresh word so that
+/// whole rows can be compared and combined a word at a time.
+///
+/// Bits beyond the end of a row are always clear. A transposed copy is kept up to date
+/// alongside, so that columns compare just as quickly as rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+    words_per_col: usize,
+    cols: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Builds a grid with every cell clear.
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        let words_per_col = height.div_ceil(WORD_BITS);
+        Self {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+            words_per_col,
+            cols: vec![0; words_per_col * width],
+        }
+    }
+
+    /// Builds a grid with the cells set wherever `grid` holds `set`.
+    pub fn from_grid(grid: &Grid2D<char>, set: char) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for y in 0..grid.height() {
+            for (x, &ch) in grid.row(y).iter().enumerate() {
+                bits.set(x, y, ch == set);
+            }
+        }
+
+        bits
+    }
+
+    /// Draws the grid as `set` and `unset` characters.
+    pub fn to_grid(&self, set: char, unset: char) -> Grid2D<char> {
+        let mut grid = Grid2D::with_size(self.width, self.height, unset);
+        for (pos, cell) in grid.iter_mut() {
+            if self.get(pos.x, pos.y) == Some(true) {
+                *cell = set;
+            }
+        }
+
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn locate(&self, x: usize, y: usize) -> Option<(usize, u64)> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let word = y * self.words_per_row + x / WORD_BITS;
+        Some((word, 1 << (x % WORD_BITS)))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        let (word, mask) = self.locate(x, y)?;
+        Some(self.words[word] & mask != 0)
+    }
+
+    /// Sets or clears the cell at `(x, y)`, ignoring positions outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        let Some((word, mask)) = self.locate(x, y) else {
+            return;
+        };
+
+        let col_word = x * self.words_per_col + y / WORD_BITS;
+        let col_mask = 1 << (y % WORD_BITS);
+        if value {
+            self.words[word] |= mask;
+            self.cols[col_word] |= col_mask;
+        } else {
+            self.words[word] &= !mask;
+            self.cols[col_word] &= !col_mask;
+        }
+    }
+
+    /// The packed words of row `y`, lowest bit first, panicking if it's out of bounds.
+    pub fn row_words(&self, y: usize) -> &[u64] {
+        assert!(y < self.height, "row {y} is out of bounds");
+        let start = y * self.words_per_row;
+        &self.words[start..start + self.words_per_row]
+    }
+
+    /// The packed words of column `x`, top cell first, panicking if it's out of bounds.
+    pub fn col_words(&self, x: usize) -> &[u64] {
+        assert!(x < self.width, "column {x} is out of bounds");
+        let start = x * self.words_per_col;
+        &self.cols[start..start + self.words_per_col]
+    }
+
+    /// The number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The number of cells that differ between rows `a` and `b`.
+    pub fn row_mismatches(&self, a: usize, b: usize) -> u32 {
+        self.row_words(a)
+            .iter()
+            .zip(self.row_words(b))
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+
+    /// The number of cells that differ between columns `a` and `b`.
+    pub fn col_mismatches(&self, a: usize, b: usize) -> u32 {
+        self.col_words(a)
+            .iter()
+            .zip(self.col_words(b))
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+
+    pub fn rows_equal(&self, a: usize, b: usize) -> bool {
+        self.row_words(a) == self.row_words(b)
+    }
+
+    pub fn cols_equal(&self, a: usize, b: usize) -> bool {
+        self.col_words(a) == self.col_words(b)
+    }
+
+    fn combine(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "can't combine a {}x{} grid with a {}x{} one",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word = op(*word, *other);
+        }
+        for (word, other) in self.cols.iter_mut().zip(&other.cols) {
+            *word = op(*word, *other);
+        }
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, rhs: &BitGrid) {
+        self.combine(rhs, |a, b| a & b);
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, rhs: &BitGrid) {
+        self.combine(rhs, |a, b| a | b);
+    }
+}
+
+impl BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, rhs: &BitGrid) {
+        self.combine(rhs, |a, b| a ^ b);
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: Self) -> BitGrid {
+        let mut result = self.clone();
+        result &= rhs;
+        result
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: Self) -> BitGrid {
+        let mut result = self.clone();
+        result |= rhs;
+        result
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: Self) -> BitGrid {
+        let mut result = self.clone();
+        result ^= rhs;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+
+    fn bits(text: &str) -> BitGrid {
+        BitGrid::from_grid(&Grid2D::from_lines(text.lines()), '#')
+    }
+
+    #[test]
+    fn it_round_trips_through_char_grids() {
+        let text = "#..#\n.##.\n#..#";
+        let grid = bits(text);
+        assert_eq!(6, grid.count_ones());
+        assert_eq!(Some(true), grid.get(3, 0));
+        assert_eq!(None, grid.get(4, 0));
+        assert_eq!(text, grid.to_grid('#', '.').to_string());
+    }
+
+    #[test]
+    fn it_compares_rows_and_columns() {
+        let grid = bits("#..#\n.##.\n#..#");
+        assert!(grid.rows_equal(0, 2));
+        assert_eq!(4, grid.row_mismatches(0, 1));
+        assert!(grid.cols_equal(1, 2));
+        assert_eq!(3, grid.col_mismatches(0, 1));
+
+        // rows longer than a word compare every word
+        let mut wide = BitGrid::new(130, 2);
+        wide.set(129, 1, true);
+        assert_eq!(1, wide.row_mismatches(0, 1));
+        assert!(!wide.rows_equal(0, 1));
+
+        // and so do columns longer than a word
+        let mut tall = BitGrid::new(2, 130);
+        tall.set(0, 0, true);
+        tall.set(1, 129, true);
+        assert_eq!(2, tall.col_mismatches(0, 1));
+        tall.set(0, 129, true);
+        tall.set(1, 0, true);
+        assert!(tall.cols_equal(0, 1));
+    }
+
+    #[test]
+    fn it_combines_grids() {
+        let a = bits("##..\n#...");
+        let b = bits("#.#.\n....");
+        assert_eq!(bits("#...\n...."), &a & &b);
+        assert_eq!(bits("###.\n#..."), &a | &b);
+        assert_eq!(bits(".##.\n#..."), &a ^ &b);
+
+        let mut c = a.clone();
+        c ^= &a;
+        assert_eq!(0, c.count_ones());
+    }
+}
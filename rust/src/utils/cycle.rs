@@ -0,0 +1,179 @@
+// Brent's search and jumping ahead have no caller yet, since day14 only needs the hashed search
+#![allow(dead_code)]
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Where a sequence of states starts repeating: state `start + len` is the same as state `start`,
+/// and no earlier pair of states match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of states before the first one that repeats.
+    pub start: usize,
+    /// The number of states in each repeat.
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The index of the first state that's the same as state `n`, which is always less than
+    /// `start + len`.
+    pub fn first_index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+}
+
+/// Finds where the states reached by repeatedly applying `step` to `initial` start repeating,
+/// using Brent's algorithm.
+///
+/// Only a couple of states are held at a time, at the cost of running `step` a few times over
+/// for each state, unlike [`find_cycle_by_key`]. Never returns if the states don't repeat.
+pub fn find_cycle<S>(initial: S, step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + PartialEq,
+{
+    match brent(initial, step, usize::MAX) {
+        Ok(cycle) => cycle,
+        Err(_) => unreachable!("ran out of states before finding a cycle"),
+    }
+}
+
+/// Finds where the states reached by repeatedly applying `step` to `initial` start repeating by
+/// remembering the `key` of every state seen, returning the cycle along with every state up to
+/// the first repeat.
+///
+/// States with the same key are treated as the same, so a cheap key such as a hash can stand in
+/// for a large state. Never returns if the states don't repeat.
+pub fn find_cycle_by_key<S, K>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    key: impl Fn(&S) -> K,
+) -> (Cycle, Vec<S>)
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        match seen.entry(key(&state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                let cycle = Cycle {
+                    start,
+                    len: states.len() - start,
+                };
+                return (cycle, states);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(states.len());
+                let next = step(&state);
+                states.push(state);
+                state = next;
+            }
+        }
+    }
+}
+
+/// The state after applying `step` to `initial` `n` times, skipping over whole repeats once the
+/// states start cycling so that huge values of `n` are cheap.
+pub fn nth_state<S>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S
+where
+    S: Clone + PartialEq,
+{
+    let cycle = match brent(initial.clone(), &mut step, n) {
+        Ok(cycle) => cycle,
+        Err(state) => return state,
+    };
+
+    (0..cycle.first_index(n)).fold(initial, |state, _| step(&state))
+}
+
+/// Brent's cycle detection, giving up and returning state `limit` if it's reached first.
+fn brent<S>(initial: S, mut step: impl FnMut(&S) -> S, limit: usize) -> Result<Cycle, S>
+where
+    S: Clone + PartialEq,
+{
+    if limit == 0 {
+        return Err(initial);
+    }
+
+    // the hare runs ahead in doubling stretches, with the tortoise waiting at the start of each
+    // one, until the hare comes back round to it
+    let mut hare = step(&initial);
+    let mut hare_index = 1;
+    let mut tortoise = initial.clone();
+    let (mut power, mut len) = (1, 1);
+    while tortoise != hare {
+        if hare_index == limit {
+            return Err(hare);
+        }
+
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+
+        hare = step(&hare);
+        hare_index += 1;
+        len += 1;
+    }
+
+    // with the hare a whole cycle ahead, they first meet where the cycle starts
+    let mut tortoise = initial.clone();
+    let mut hare = (0..len).fold(initial, |state, _| step(&state));
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Ok(Cycle { start, len })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::cycle::*;
+
+    /// Cycles through 7, 15, 14, 12, 8, 0, 1, 3 and back to 7.
+    fn step(&n: &u64) -> u64 {
+        (n * 2 + 1) % 17
+    }
+
+    #[test]
+    fn it_finds_cycles_both_ways() {
+        let cycle = find_cycle(7, step);
+        let (hashed, states) = find_cycle_by_key(7, step, |&n| n);
+        assert_eq!(cycle, hashed);
+        assert_eq!(Cycle { start: 0, len: 8 }, cycle);
+        assert_eq!(8, states.len());
+
+        // 16 maps to itself straight away, after an unrepeated 0
+        let cycle = find_cycle(0, |&n| if n == 0 { 16 } else { step(&n) });
+        assert_eq!(Cycle { start: 1, len: 1 }, cycle);
+        assert_eq!(1, cycle.first_index(1_000_000_000));
+    }
+
+    #[test]
+    fn it_jumps_ahead_to_the_nth_state() {
+        let naive = |n| (0..n).fold(1, |state, _| step(&state));
+        for n in [0, 1, 5, 40, 1234] {
+            assert_eq!(naive(n), nth_state(1, step, n));
+        }
+
+        // counting up never repeats, which is fine as long as the answer comes first
+        assert_eq!(10, nth_state(0_u64, |&n| n + 1, 10));
+        // the states repeat every 8 steps from the start, so far ahead lands back near the start
+        assert_eq!(
+            nth_state(1, step, 1_000_000_003 % 8),
+            nth_state(1, step, 1_000_000_003)
+        );
+    }
+}
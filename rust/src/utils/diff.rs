@@ -0,0 +1,304 @@
+// display_char is only reached through assert_grid_eq!, which only tests use
+#![allow(dead_code)]
+
+use std::{
+    fmt::{Display, Write},
+    io::{self, IsTerminal},
+};
+
+use crossterm::{
+    style::{Color, ResetColor, SetForegroundColor},
+    Command,
+};
+
+use super::{Grid2D, Point};
+
+/// A cell whose value differs between two grids.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Change<'a, T> {
+    pub pos: Point,
+    pub before: &'a T,
+    pub after: &'a T,
+}
+
+impl<T> Clone for Change<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Change<'_, T> {}
+
+/// The differences between two grids, as returned by [`Grid2D::diff`].
+pub struct GridDiff<'a, T> {
+    before: &'a Grid2D<T>,
+    after: &'a Grid2D<T>,
+    changes: Vec<Change<'a, T>>,
+}
+
+impl<T> Grid2D<T>
+where
+    T: PartialEq,
+{
+    /// Compares the grid to `other`, cell by cell.
+    ///
+    /// Only the cells the two grids have in common are compared, so grids of different sizes
+    /// also need [`GridDiff::resized`] checking.
+    pub fn diff<'a>(&'a self, other: &'a Self) -> GridDiff<'a, T> {
+        let (width, height) = (self.width.min(other.width), self.height.min(other.height));
+        let mut changes = vec![];
+        for y in 0..height {
+            for (x, (before, after)) in self.row(y)[..width]
+                .iter()
+                .zip(&other.row(y)[..width])
+                .enumerate()
+            {
+                if before != after {
+                    changes.push(Change {
+                        pos: Point::new(x, y),
+                        before,
+                        after,
+                    });
+                }
+            }
+        }
+
+        GridDiff {
+            before: self,
+            after: other,
+            changes,
+        }
+    }
+}
+
+impl<'a, T> GridDiff<'a, T> {
+    /// The cells that changed, row by row.
+    pub fn changes(&self) -> &[Change<'a, T>] {
+        &self.changes
+    }
+
+    pub fn resized(&self) -> bool {
+        (self.before.width, self.before.height) != (self.after.width, self.after.height)
+    }
+
+    /// Whether the grids are the same size with the same cells.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && !self.resized()
+    }
+
+    /// Draws the grids next to each other, followed by a column marking changed cells with `X`.
+    pub fn side_by_side<F>(&self, to_char: F) -> DiffDisplay<'_, 'a, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        self.display(to_char, Layout::SideBySide)
+    }
+
+    /// Draws the second grid, followed by a line for each changed cell.
+    pub fn inline<F>(&self, to_char: F) -> DiffDisplay<'_, 'a, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        self.display(to_char, Layout::Inline)
+    }
+
+    fn display<F>(&self, to_char: F, layout: Layout) -> DiffDisplay<'_, 'a, T, F> {
+        let mut changed = Grid2D::with_size(self.after.width, self.after.height, false);
+        for change in &self.changes {
+            changed.replace(true, change.pos.x, change.pos.y);
+        }
+
+        DiffDisplay {
+            diff: self,
+            to_char,
+            layout,
+            changed,
+            ansi: io::stdout().is_terminal(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Layout {
+    SideBySide,
+    Inline,
+}
+
+/// Draws a [`GridDiff`], coloring cells that were changed when writing to a terminal.
+pub struct DiffDisplay<'d, 'a, T, F> {
+    diff: &'d GridDiff<'a, T>,
+    to_char: F,
+    layout: Layout,
+    changed: Grid2D<bool>,
+    ansi: bool,
+}
+
+impl<T, F> DiffDisplay<'_, '_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    /// Forces colors on or off, whatever stdout is connected to.
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    /// Writes row `y` of `grid`, padded out to `width` and with changed cells in `color`.
+    fn write_row(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        grid: &Grid2D<T>,
+        y: usize,
+        width: usize,
+        color: Color,
+    ) -> std::fmt::Result {
+        let cells = if y < grid.height { grid.row(y) } else { &[] };
+        for x in 0..width {
+            let Some(cell) = cells.get(x) else {
+                f.write_char(' ')?;
+                continue;
+            };
+
+            let changed = self.ansi && self.changed.get(x, y) == Some(&true);
+            if changed {
+                SetForegroundColor(color).write_ansi(f)?;
+            }
+            f.write_char((self.to_char)(cell))?;
+            if changed {
+                ResetColor.write_ansi(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, F> Display for DiffDisplay<'_, '_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (before, after) = (self.diff.before, self.diff.after);
+        if self.diff.resized() {
+            writeln!(
+                f,
+                "resized from {}x{} to {}x{}",
+                before.width, before.height, after.width, after.height
+            )?;
+        }
+
+        match self.layout {
+            Layout::SideBySide => {
+                for y in 0..before.height.max(after.height) {
+                    self.write_row(f, before, y, before.width, Color::Red)?;
+                    f.write_str("  ")?;
+                    self.write_row(f, after, y, after.width, Color::Green)?;
+                    f.write_str("  ")?;
+                    for x in 0..after.width {
+                        let changed = self.changed.get(x, y) == Some(&true);
+                        f.write_char(if changed { 'X' } else { '.' })?;
+                    }
+                    f.write_char('\n')?;
+                }
+            }
+            Layout::Inline => {
+                for y in 0..after.height {
+                    self.write_row(f, after, y, after.width, Color::Green)?;
+                    f.write_char('\n')?;
+                }
+
+                for change in &self.diff.changes {
+                    writeln!(
+                        f,
+                        "({}, {}): {} -> {}",
+                        change.pos.x,
+                        change.pos.y,
+                        (self.to_char)(change.before),
+                        (self.to_char)(change.after)
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The character a cell is drawn as by [`assert_grid_eq!`] when no drawing function is given,
+/// which is the first character of its [`Display`] output.
+pub fn display_char(cell: &impl Display) -> char {
+    cell.to_string().chars().next().unwrap_or(' ')
+}
+
+/// Asserts that two grids are equal, printing the cells that differ side by side if they're not.
+///
+/// Cells are drawn with their [`Display`] implementation, or with a `Fn(&T) -> char` passed as a
+/// third argument.
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_grid_eq!($left, $right, $crate::utils::diff::display_char)
+    };
+    ($left:expr, $right:expr, $to_char:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        let diff = left.diff(right);
+        if !diff.is_empty() {
+            panic!(
+                "grids differ, changed cells: {} (left, right, changes)\n{}",
+                diff.changes().len(),
+                diff.side_by_side($to_char).ansi(false)
+            );
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+
+    fn grids() -> (Grid2D<char>, Grid2D<char>) {
+        (
+            Grid2D::from_lines("ab\ncd".lines()),
+            Grid2D::from_lines("ab\nxd".lines()),
+        )
+    }
+
+    #[test]
+    fn it_finds_changed_cells() {
+        let (before, after) = grids();
+        let diff = before.diff(&after);
+        assert_eq!(1, diff.changes().len());
+        let change = diff.changes()[0];
+        assert_eq!(
+            (Point::new(0, 1), &'c', &'x'),
+            (change.pos, change.before, change.after)
+        );
+        assert!(before.diff(&before).is_empty());
+
+        let wider = Grid2D::from_lines("abz\ncdz".lines());
+        let diff = before.diff(&wider);
+        assert!(diff.changes().is_empty() && !diff.is_empty());
+    }
+
+    #[test]
+    fn it_draws_diffs() {
+        let (before, after) = grids();
+        let diff = before.diff(&after);
+        assert_eq!(
+            "ab  ab  ..\ncd  xd  X.\n",
+            diff.side_by_side(|&ch| ch).ansi(false).to_string()
+        );
+        assert_eq!(
+            "ab\nxd\n(0, 1): c -> x\n",
+            diff.inline(|&ch| ch).ansi(false).to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "grids differ, changed cells: 1")]
+    fn it_asserts_grids_are_equal() {
+        let (before, after) = grids();
+        crate::assert_grid_eq!(before, before.clone());
+        crate::assert_grid_eq!(before, after, |&ch| ch);
+    }
+}
@@ -0,0 +1,153 @@
+use super::Vec2;
+
+/// One of the four orthogonal compass directions, with north pointing up the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Turns 90 degrees anticlockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Self::North => Vec2::new(0, -1),
+            Self::East => Vec2::new(1, 0),
+            Self::South => Vec2::new(0, 1),
+            Self::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl From<Direction4> for Vec2 {
+    fn from(dir: Direction4) -> Self {
+        dir.delta()
+    }
+}
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Turns 45 degrees anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Self::North => Vec2::new(0, -1),
+            Self::NorthEast => Vec2::new(1, -1),
+            Self::East => Vec2::new(1, 0),
+            Self::SouthEast => Vec2::new(1, 1),
+            Self::South => Vec2::new(0, 1),
+            Self::SouthWest => Vec2::new(-1, 1),
+            Self::West => Vec2::new(-1, 0),
+            Self::NorthWest => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        match dir {
+            Direction4::North => Self::North,
+            Direction4::East => Self::East,
+            Direction4::South => Self::South,
+            Direction4::West => Self::West,
+        }
+    }
+}
+
+impl From<Direction8> for Vec2 {
+    fn from(dir: Direction8) -> Self {
+        dir.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::direction::*;
+
+    #[test]
+    fn it_turns_orthogonally() {
+        assert_eq!(Direction4::West, Direction4::North.turn_left());
+        assert_eq!(Direction4::East, Direction4::North.turn_right());
+        assert_eq!(Direction4::South, Direction4::North.opposite());
+        for dir in Direction4::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(-dir.delta(), dir.opposite().delta());
+        }
+    }
+
+    #[test]
+    fn it_turns_diagonally() {
+        assert_eq!(Direction8::NorthWest, Direction8::North.turn_left());
+        assert_eq!(Direction8::North, Direction8::NorthWest.turn_right());
+        assert_eq!(Direction8::SouthWest, Direction8::NorthEast.opposite());
+        for dir in Direction8::ALL {
+            assert_eq!(-dir.delta(), dir.opposite().delta());
+        }
+    }
+}
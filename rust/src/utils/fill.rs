@@ -0,0 +1,125 @@
+use super::{Grid2D, Point};
+
+/// The orthogonally connected regions of a grid, as found by [`Grid2D::connected_components`].
+#[derive(Clone)]
+pub struct Components {
+    /// The component each cell belongs to, or `None` for cells that didn't match.
+    pub labels: Grid2D<Option<usize>>,
+    /// The number of cells in each component, indexed by label.
+    pub sizes: Vec<usize>,
+}
+
+impl<T> Grid2D<T> {
+    /// Marks every cell reachable from `start` by orthogonal steps through `passable` cells.
+    ///
+    /// Nothing is marked if `start` itself isn't passable.
+    pub fn flood_fill(&self, start: Point, passable: impl Fn(&T) -> bool) -> Grid2D<bool> {
+        let mut filled = Grid2D::with_size(self.width, self.height, false);
+        self.fill_from([start], &passable, &mut filled, |_| {});
+        filled
+    }
+
+    /// Marks every cell reachable from the edge of the grid through `passable` cells, which leaves
+    /// the passable cells that are walled in unmarked.
+    pub fn exterior_fill(&self, passable: impl Fn(&T) -> bool) -> Grid2D<bool> {
+        let (width, height) = (self.width, self.height);
+        let border = (0..width)
+            .flat_map(|x| [Point::new(x, 0), Point::new(x, height.saturating_sub(1))])
+            .chain(
+                (0..height)
+                    .flat_map(|y| [Point::new(0, y), Point::new(width.saturating_sub(1), y)]),
+            );
+
+        let mut filled = Grid2D::with_size(width, height, false);
+        self.fill_from(border, &passable, &mut filled, |_| {});
+        filled
+    }
+
+    /// Splits the cells matching `predicate` into orthogonally connected components, labelled in
+    /// the order their first cell appears reading row by row.
+    pub fn connected_components(&self, predicate: impl Fn(&T) -> bool) -> Components {
+        let mut seen = Grid2D::with_size(self.width, self.height, false);
+        let mut labels = Grid2D::with_size(self.width, self.height, None);
+        let mut sizes = vec![];
+        for start in self.positions() {
+            if seen.get(start.x, start.y) == Some(&true) {
+                continue;
+            }
+
+            let label = sizes.len();
+            let mut size = 0;
+            self.fill_from([start], &predicate, &mut seen, |pos| {
+                labels.replace(Some(label), pos.x, pos.y);
+                size += 1;
+            });
+
+            if size > 0 {
+                sizes.push(size);
+            }
+        }
+
+        Components { labels, sizes }
+    }
+
+    /// Marks every unmarked, passable cell reachable from `starts` in `filled`, calling `visit`
+    /// once for each.
+    fn fill_from(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        passable: &impl Fn(&T) -> bool,
+        filled: &mut Grid2D<bool>,
+        mut visit: impl FnMut(Point),
+    ) {
+        let mut pending = starts.into_iter().collect::<Vec<_>>();
+        while let Some(pos) = pending.pop() {
+            let is_new = filled.get(pos.x, pos.y) == Some(&false);
+            if !is_new || !self.get(pos.x, pos.y).is_some_and(passable) {
+                continue;
+            }
+
+            filled.replace(true, pos.x, pos.y);
+            visit(pos);
+            pending.extend(self.neighbors4(pos));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+
+    fn ring() -> Grid2D<char> {
+        Grid2D::from_lines(".....\n.###.\n.#.#.\n.###.\n....#".lines())
+    }
+
+    #[test]
+    fn it_floods_from_a_point() {
+        let filled = ring().flood_fill(Point::new(0, 0), |&ch| ch == '.');
+        assert_eq!(
+            "#####\n#...#\n#...#\n#...#\n####.",
+            filled
+                .display_with(|&f| if f { '#' } else { '.' })
+                .to_string()
+        );
+
+        let walls = ring().flood_fill(Point::new(1, 1), |&ch| ch == '.');
+        assert!(walls.view().iter().all(|&f| !f));
+    }
+
+    #[test]
+    fn it_floods_from_the_border() {
+        let exterior = ring().exterior_fill(|&ch| ch == '.');
+        assert_eq!(Some(&false), exterior.get(2, 2));
+        assert_eq!(Some(&true), exterior.get(4, 3));
+        assert_eq!(15, exterior.view().iter().filter(|&&f| f).count());
+    }
+
+    #[test]
+    fn it_labels_connected_components() {
+        let components = ring().connected_components(|&ch| ch == '#');
+        assert_eq!(vec![8, 1], components.sizes);
+        assert_eq!(Some(&Some(0)), components.labels.get(3, 3));
+        assert_eq!(Some(&Some(1)), components.labels.get(4, 4));
+        assert_eq!(Some(&None), components.labels.get(2, 2));
+    }
+}
@@ -0,0 +1,318 @@
+// the recorder's remaining knobs are only used by tests so far
+#![allow(dead_code)]
+
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+use super::{image::Rgb, scoped, Grid2D};
+
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+const DEFAULT_MAX_FRAMES: usize = 1000;
+
+thread_local! {
+    static RECORDER: RefCell<Option<FrameRecorder>> = const { RefCell::new(None) };
+}
+
+/// Collects snapshots of a grid as it changes, to be saved as an animated GIF or a directory of
+/// numbered PPMs.
+///
+/// Frames are stored as indices into the recorder's palette, so every snapshot has to be mapped
+/// to a palette index per cell as it's taken.
+pub struct FrameRecorder {
+    palette: Vec<Rgb>,
+    delay: Duration,
+    interval: usize,
+    scale: usize,
+    max_frames: usize,
+    steps: usize,
+    frames: Vec<Grid2D<u8>>,
+}
+
+impl FrameRecorder {
+    /// Builds a recorder that keeps every step, panicking unless the palette has between 1 and
+    /// 256 colors.
+    pub fn new(palette: Vec<Rgb>) -> Self {
+        assert!(
+            (1..=256).contains(&palette.len()),
+            "palettes need between 1 and 256 colors, not {}",
+            palette.len()
+        );
+
+        Self {
+            palette,
+            delay: DEFAULT_DELAY,
+            interval: 1,
+            scale: 1,
+            max_frames: DEFAULT_MAX_FRAMES,
+            steps: 0,
+            frames: vec![],
+        }
+    }
+
+    /// Sets how long each frame is shown for, to the nearest hundredth of a second.
+    pub fn with_delay(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+
+    /// Only keeps every `interval`th step passed to [`FrameRecorder::record`].
+    pub fn with_interval(self, interval: usize) -> Self {
+        assert!(interval > 0, "frames can't be kept every 0 steps");
+        Self { interval, ..self }
+    }
+
+    /// Sets how many pixels wide each cell is drawn.
+    pub fn with_scale(self, scale: usize) -> Self {
+        assert!(
+            scale > 0,
+            "frames must be drawn at least one pixel per cell"
+        );
+        Self { scale, ..self }
+    }
+
+    /// Sets how many frames [`FrameRecorder::record`] keeps before dropping later steps, so that
+    /// long running simulations can't fill up memory.
+    pub fn with_max_frames(self, max_frames: usize) -> Self {
+        Self { max_frames, ..self }
+    }
+
+    /// Counts a step of the simulation, keeping a snapshot of `grid` if it falls on the
+    /// recorder's interval. The first step is always kept.
+    pub fn record<T>(&mut self, grid: &Grid2D<T>, index: impl Fn(&T) -> u8) {
+        if self.steps.is_multiple_of(self.interval) && self.frames.len() < self.max_frames {
+            self.push(grid, index);
+        }
+
+        self.steps += 1;
+    }
+
+    /// Keeps a snapshot of `grid` whatever the interval or frame limit, for frames that
+    /// shouldn't be skipped such as the final state.
+    ///
+    /// Panics if `index` maps a cell past the end of the palette.
+    pub fn push<T>(&mut self, grid: &Grid2D<T>, index: impl Fn(&T) -> u8) {
+        let frame = grid.map(index);
+        if let Some(index) = frame
+            .view()
+            .iter()
+            .find(|&&i| i as usize >= self.palette.len())
+        {
+            panic!("{index} is outside the palette");
+        }
+
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Grid2D<u8>] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The frame's palette indices with each cell repeated into a `scale` pixel square.
+    fn scaled(&self, frame: &Grid2D<u8>) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(frame.width() * frame.height() * self.scale.pow(2));
+        for y in 0..frame.height() {
+            let line = frame
+                .row(y)
+                .iter()
+                .flat_map(|&index| [index].repeat(self.scale))
+                .collect::<Vec<_>>();
+
+            for _ in 0..self.scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        pixels
+    }
+
+    /// Writes the frames as a looping animated GIF, panicking if they aren't all the same size.
+    ///
+    /// GIFs are at most 65535 pixels across, so scaled up frames any larger are rejected.
+    pub fn write_gif(&self, out: impl Write) -> io::Result<()> {
+        let Some(first) = self.frames.first() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "no frames were recorded",
+            ));
+        };
+
+        let dimension = |cells: usize| {
+            u16::try_from(cells * self.scale).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "{}x{} frames scaled by {} are too large for a GIF",
+                        first.width(),
+                        first.height(),
+                        self.scale
+                    ),
+                )
+            })
+        };
+        let (width, height) = (dimension(first.width())?, dimension(first.height())?);
+        let palette = self
+            .palette
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect::<Vec<_>>();
+        let mut encoder =
+            gif::Encoder::new(out, width, height, &palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        let delay = (self.delay.as_millis() / 10).min(u16::MAX.into()) as u16;
+        for frame in &self.frames {
+            assert!(
+                frame.width() == first.width() && frame.height() == first.height(),
+                "every frame must be {}x{}",
+                first.width(),
+                first.height()
+            );
+
+            let frame = gif::Frame {
+                width,
+                height,
+                delay,
+                buffer: Cow::Owned(self.scaled(frame)),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes each frame into `dir` as `frame_0000.ppm`, `frame_0001.ppm` and so on, creating
+    /// the directory if needed.
+    pub fn save_ppms(&self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            let out = BufWriter::new(File::create(dir.join(format!("frame_{i:04}.ppm")))?);
+            frame.write_ppm(out, self.scale, |&index| self.palette[index as usize])?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the frames as a GIF if `path` ends in `.gif`, or as a directory of PPMs otherwise.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if path.extension().is_some_and(|ext| ext == "gif") {
+            let mut out = BufWriter::new(File::create(path)?);
+            self.write_gif(&mut out)?;
+            out.flush()
+        } else {
+            self.save_ppms(path)
+        }
+    }
+}
+
+/// Whether a recorder is listening on this thread, so that solvers can skip building frames
+/// nobody will see.
+pub fn is_recording() -> bool {
+    RECORDER.with(|recorder| recorder.borrow().is_some())
+}
+
+/// Counts a step of the simulation for the recorder listening on this thread, as with
+/// [`FrameRecorder::record`].
+///
+/// Solvers can call this as often as they like; it does nothing unless a recorder is listening.
+pub fn record<T>(grid: &Grid2D<T>, index: impl Fn(&T) -> u8) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut() {
+            recorder.record(grid, index);
+        }
+    });
+}
+
+/// Keeps a snapshot for the recorder listening on this thread, as with [`FrameRecorder::push`].
+pub fn push<T>(grid: &Grid2D<T>, index: impl Fn(&T) -> u8) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut() {
+            recorder.push(grid, index);
+        }
+    });
+}
+
+/// Runs `f` with `recorder` listening to the frames recorded on this thread, handing it back
+/// afterwards along with the result.
+pub fn with_recorder<T>(recorder: FrameRecorder, f: impl FnOnce() -> T) -> (T, FrameRecorder) {
+    let (result, recorder) = scoped::with_hook(&RECORDER, recorder, f);
+    (
+        result,
+        recorder.expect("the recorder was taken while recording"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::frames::*;
+
+    fn palette() -> Vec<Rgb> {
+        vec![Rgb(0, 0, 0), Rgb(255, 255, 255)]
+    }
+
+    fn frame(lit: usize) -> Grid2D<bool> {
+        let mut grid = Grid2D::with_size(3, 1, false);
+        grid.replace(true, lit, 0);
+        grid
+    }
+
+    #[test]
+    fn it_keeps_every_nth_step() {
+        let mut recorder = FrameRecorder::new(palette())
+            .with_interval(2)
+            .with_max_frames(2);
+        for lit in 0..3 {
+            recorder.record(&frame(lit), |&lit| lit as u8);
+        }
+        assert_eq!(2, recorder.len());
+        assert_eq!(Some(&1), recorder.frames()[1].get(2, 0));
+
+        recorder.record(&frame(1), |&lit| lit as u8);
+        assert_eq!(2, recorder.len());
+        recorder.push(&frame(1), |&lit| lit as u8);
+        assert_eq!(3, recorder.len());
+    }
+
+    #[test]
+    fn it_records_through_the_thread_local_hook() {
+        record(&frame(0), |&lit| lit as u8);
+
+        let ((), recorder) = with_recorder(FrameRecorder::new(palette()), || {
+            record(&frame(0), |&lit| lit as u8);
+            push(&frame(1), |&lit| lit as u8);
+        });
+        assert_eq!(2, recorder.len());
+
+        let mut gif = vec![];
+        recorder.with_scale(2).write_gif(&mut gif).unwrap();
+        assert_eq!(b"GIF89a", &gif[..6]);
+        // the logical screen is the scaled up frame size
+        assert_eq!(&[6, 0, 2, 0], &gif[6..10]);
+    }
+
+    #[test]
+    fn it_rejects_frames_too_large_for_a_gif() {
+        let mut recorder = FrameRecorder::new(palette()).with_scale(30_000);
+        recorder.push(&frame(0), |&lit| lit as u8);
+
+        let err = recorder.write_gif(vec![]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+    }
+}
@@ -0,0 +1,195 @@
+//! Measurements of simple polygons given as their corners in order, in either direction, with
+//! the last corner joining back up to the first.
+//!
+//! Corners are `(x, y)` pairs of any signed integer type, so `i128` can stand in for `i64` when
+//! areas get too large.
+
+// only the lattice point counts have callers so far, the rest round out the toolbox
+#![allow(dead_code)]
+
+use super::math::{gcd, Signed};
+
+/// Where a point lies relative to a polygon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Inside,
+    Outside,
+    /// On one of the polygon's edges, including its corners.
+    Boundary,
+}
+
+/// The edges of the polygon as pairs of corners, including the one closing it back up.
+fn edges<T: Copy>(vertices: &[(T, T)]) -> impl Iterator<Item = ((T, T), (T, T))> + '_ {
+    vertices
+        .iter()
+        .copied()
+        .zip(vertices.iter().copied().cycle().skip(1))
+}
+
+/// Twice the area of the polygon using the shoelace formula, which keeps the result whole for
+/// corners on the lattice.
+///
+/// The area is positive when the corners go anticlockwise with `y` growing upwards, which is
+/// clockwise on a grid where `y` grows downwards.
+pub fn shoelace<T: Signed>(vertices: &[(T, T)]) -> T {
+    edges(vertices).fold(T::ZERO, |total, ((x1, y1), (x2, y2))| {
+        total + x1 * y2 - x2 * y1
+    })
+}
+
+/// The area of the polygon, rounded down when it's a half.
+pub fn area<T: Signed>(vertices: &[(T, T)]) -> T {
+    shoelace(vertices).magnitude() / (T::ONE + T::ONE)
+}
+
+/// The total length of the polygon's edges.
+pub fn perimeter<T: Signed>(vertices: &[(T, T)]) -> f64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| (x2 - x1).to_f64().hypot((y2 - y1).to_f64()))
+        .sum()
+}
+
+/// The number of lattice points on the polygon's edges, which is the length of its perimeter
+/// when every edge is horizontal or vertical.
+pub fn boundary_points<T: Signed>(vertices: &[(T, T)]) -> T {
+    edges(vertices).fold(T::ZERO, |total, ((x1, y1), (x2, y2))| {
+        total + gcd(x2 - x1, y2 - y1)
+    })
+}
+
+/// The number of lattice points strictly inside the polygon, using Pick's theorem.
+pub fn interior_points<T: Signed>(vertices: &[(T, T)]) -> T {
+    let two = T::ONE + T::ONE;
+    (shoelace(vertices).magnitude() - boundary_points(vertices) + two) / two
+}
+
+/// The number of lattice points inside or on the polygon, such as the number of cells covered
+/// when the corners are the centres of grid cells.
+pub fn lattice_points<T: Signed>(vertices: &[(T, T)]) -> T {
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+/// Whether `point` lies on the edge from `a` to `b`.
+fn on_edge<T: Signed>((px, py): (T, T), (a, b): ((T, T), (T, T))) -> bool {
+    let cross = (b.0 - a.0) * (py - a.1) - (b.1 - a.1) * (px - a.0);
+    cross == T::ZERO
+        && a.0.min(b.0) <= px
+        && px <= a.0.max(b.0)
+        && a.1.min(b.1) <= py
+        && py <= a.1.max(b.1)
+}
+
+/// Where `point` lies relative to a polygon with edges at any angle, by counting the edges
+/// crossed on the way out to the right.
+pub fn point_in_polygon<T: Signed>(vertices: &[(T, T)], point: (T, T)) -> Containment {
+    let (px, py) = point;
+    let mut inside = false;
+    for edge in edges(vertices) {
+        if on_edge(point, edge) {
+            return Containment::Boundary;
+        }
+
+        // each edge counts for the rows from its lower end up to but not including its upper
+        // end, so a corner the ray passes through is only crossed once
+        let ((x1, y1), (x2, y2)) = edge;
+        if (y1 > py) != (y2 > py) {
+            // the sign of where the edge crosses the row, relative to the point, without dividing
+            let side = (x2 - x1) * (py - y1) - (px - x1) * (y2 - y1);
+            if (side > T::ZERO) == (y2 > y1) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
+
+/// Where `point` lies relative to a polygon whose edges are all horizontal or vertical, which
+/// only needs the vertical edges to the right of the point checking.
+pub fn point_in_rectilinear<T: Signed>(vertices: &[(T, T)], point: (T, T)) -> Containment {
+    let (px, py) = point;
+    let mut inside = false;
+    for edge in edges(vertices) {
+        let ((x1, y1), (x2, y2)) = edge;
+        debug_assert!(
+            x1 == x2 || y1 == y2,
+            "{edge:?} isn't horizontal or vertical"
+        );
+        if on_edge(point, edge) {
+            return Containment::Boundary;
+        }
+
+        if x1 == x2 && x1 > px && (y1 > py) != (y2 > py) {
+            inside = !inside;
+        }
+    }
+
+    if inside {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::geometry::*;
+
+    /// An L shape, 6 wide from y = 2 to 4 and 2 wide from there down to y = 0.
+    const L_SHAPE: [(i64, i64); 6] = [(0, 0), (0, 4), (6, 4), (6, 2), (2, 2), (2, 0)];
+
+    #[test]
+    fn it_measures_polygons() {
+        assert_eq!(-32, shoelace(&L_SHAPE));
+        assert_eq!(16, area(&L_SHAPE));
+        assert_eq!(20.0, perimeter(&L_SHAPE));
+        assert_eq!(20, boundary_points(&L_SHAPE));
+        assert_eq!(7, interior_points(&L_SHAPE));
+
+        // a triangle with an area of one and a half, and a lattice point along its bottom edge
+        let triangle = [(0_i128, 0_i128), (3, 0), (1, 1)];
+        assert_eq!(3, shoelace(&triangle));
+        assert_eq!(1, area(&triangle));
+        assert_eq!(5, boundary_points(&triangle));
+        assert_eq!(0, interior_points(&triangle));
+        assert_eq!(5, lattice_points(&triangle));
+    }
+
+    #[test]
+    fn it_locates_points() {
+        for y in -1..=5 {
+            for x in -1..=7 {
+                let expected = if L_SHAPE
+                    .iter()
+                    .zip(L_SHAPE.iter().cycle().skip(1))
+                    .any(|(&a, &b)| on_edge((x, y), (a, b)))
+                {
+                    Containment::Boundary
+                } else if (0..=2).contains(&x) && (0..=4).contains(&y)
+                    || (0..=6).contains(&x) && (2..=4).contains(&y)
+                {
+                    Containment::Inside
+                } else {
+                    Containment::Outside
+                };
+                assert_eq!(expected, point_in_polygon(&L_SHAPE, (x, y)), "({x}, {y})");
+                assert_eq!(
+                    expected,
+                    point_in_rectilinear(&L_SHAPE, (x, y)),
+                    "({x}, {y})"
+                );
+            }
+        }
+
+        // rays out along y = 1 pass straight through the corners at (1, 1) and (5, 1)
+        let diamond = [(3, -1), (5, 1), (3, 3), (1, 1)];
+        assert_eq!(Containment::Boundary, point_in_polygon(&diamond, (1, 1)));
+        assert_eq!(Containment::Inside, point_in_polygon(&diamond, (3, 1)));
+        assert_eq!(Containment::Outside, point_in_polygon(&diamond, (0, 1)));
+        assert_eq!(Containment::Outside, point_in_polygon(&diamond, (6, 1)));
+    }
+}
@@ -0,0 +1,276 @@
+// no puzzle has needed a third dimension yet
+#![allow(dead_code)]
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::Grid2D;
+
+/// A position or offset in three dimensions.
+///
+/// Coordinates are signed so the same type can describe velocities and positions outside a
+/// [`Grid3D`]; the grid only holds the ones between zero and its size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    /// The offsets to the six points sharing a face with the origin.
+    pub const FACES: [Self; 6] = [
+        Self::new(0, 0, -1),
+        Self::new(0, -1, 0),
+        Self::new(-1, 0, 0),
+        Self::new(1, 0, 0),
+        Self::new(0, 1, 0),
+        Self::new(0, 0, 1),
+    ];
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan_len(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs() + self.z.unsigned_abs()
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        (self - other).manhattan_len()
+    }
+
+    pub fn dot(self, other: Self) -> i64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// The offsets to the 26 points sharing a face, edge or corner with the origin, ordered by
+    /// `z`, then `y`, then `x`.
+    pub fn around() -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| Self::new(x, y, z))))
+            .filter(|&offset| offset != Self::default())
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+/// A box of cells stored as a stack of `depth` layers, each laid out like a [`Grid2D`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid3D<T> {
+    width: usize,
+    height: usize,
+    depth: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid3D<T>
+where
+    T: Clone,
+{
+    pub fn with_size(width: usize, height: usize, depth: usize, default: T) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+            cells: vec![default; width * height * depth],
+        }
+    }
+
+    /// Copies layer `z` out as a grid, panicking if it's out of bounds.
+    pub fn layer(&self, z: usize) -> Grid2D<T> {
+        assert!(z < self.depth, "layer {z} is out of bounds");
+        let size = self.width * self.height;
+        Grid2D {
+            height: self.height,
+            width: self.width,
+            stride: self.width,
+            cells: self.cells[z * size..(z + 1) * size].to_vec(),
+        }
+    }
+}
+
+impl<T> Grid3D<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn index(&self, x: usize, y: usize, z: usize) -> Option<usize> {
+        if x >= self.width || y >= self.height || z >= self.depth {
+            return None;
+        }
+
+        Some((z * self.height + y) * self.width + x)
+    }
+
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&T> {
+        let idx = self.index(x, y, z)?;
+        self.cells.get(idx)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize, z: usize) -> Option<&mut T> {
+        let idx = self.index(x, y, z)?;
+        self.cells.get_mut(idx)
+    }
+
+    pub fn replace(&mut self, item: T, x: usize, y: usize, z: usize) {
+        if let Some(entry) = self.get_mut(x, y, z) {
+            *entry = item;
+        }
+    }
+
+    pub fn contains(&self, point: Point3) -> bool {
+        self.to_indices(point).is_some()
+    }
+
+    fn to_indices(&self, point: Point3) -> Option<(usize, usize, usize)> {
+        let (x, y, z) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+            usize::try_from(point.z).ok()?,
+        );
+        self.index(x, y, z).map(|_| (x, y, z))
+    }
+
+    /// Looks up `point`, returning `None` if it's outside the grid, including on the negative
+    /// side of any axis.
+    pub fn get_point(&self, point: Point3) -> Option<&T> {
+        let (x, y, z) = self.to_indices(point)?;
+        self.get(x, y, z)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point3) -> Option<&mut T> {
+        let (x, y, z) = self.to_indices(point)?;
+        self.get_mut(x, y, z)
+    }
+
+    /// The neighbors of `point` sharing a face with it that lie within the grid.
+    pub fn neighbors6(&self, point: Point3) -> impl Iterator<Item = Point3> + '_ {
+        Point3::FACES
+            .into_iter()
+            .map(move |offset| point + offset)
+            .filter(|&next| self.contains(next))
+    }
+
+    /// The neighbors of `point` sharing a face, edge or corner with it that lie within the grid.
+    pub fn neighbors26(&self, point: Point3) -> impl Iterator<Item = Point3> + '_ {
+        Point3::around()
+            .map(move |offset| point + offset)
+            .filter(|&next| self.contains(next))
+    }
+
+    /// Every cell along with its position, layer by layer and then row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point3, &T)> {
+        let (width, height) = (self.width, self.height);
+        self.cells.iter().enumerate().map(move |(idx, cell)| {
+            let (x, y, z) = (idx % width, idx / width % height, idx / (width * height));
+            (Point3::new(x as i64, y as i64, z as i64), cell)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid3d::*;
+
+    #[test]
+    fn it_does_vector_arithmetic() {
+        let a = Point3::new(1, -2, 3);
+        assert_eq!(Point3::new(2, -4, 6), a * 2);
+        assert_eq!(Point3::default(), a + -a);
+        assert_eq!(6, a.manhattan_len());
+        assert_eq!(14, a.dot(a));
+        assert_eq!(
+            Point3::new(0, 0, 1),
+            Point3::new(1, 0, 0).cross(Point3::new(0, 1, 0))
+        );
+        assert_eq!(26, Point3::around().count());
+    }
+
+    #[test]
+    fn it_stores_cells_in_layers() {
+        let mut grid = Grid3D::with_size(3, 2, 2, '.');
+        grid.replace('#', 2, 1, 1);
+        *grid.get_point_mut(Point3::new(0, 0, 1)).unwrap() = '@';
+        assert_eq!(Some(&'#'), grid.get(2, 1, 1));
+        assert_eq!(None, grid.get(3, 0, 0));
+        assert_eq!(None, grid.get_point(Point3::new(0, -1, 0)));
+        assert_eq!("@..\n..#", grid.layer(1).to_string());
+        assert_eq!(
+            Some((Point3::new(2, 1, 1), &'#')),
+            grid.iter().find(|(_, &ch)| ch == '#')
+        );
+    }
+
+    #[test]
+    fn it_finds_neighbors_within_bounds() {
+        let grid = Grid3D::with_size(3, 3, 3, 0);
+        let corner = Point3::new(0, 0, 0);
+        assert_eq!(3, grid.neighbors6(corner).count());
+        assert_eq!(7, grid.neighbors26(corner).count());
+        assert_eq!(26, grid.neighbors26(Point3::new(1, 1, 1)).count());
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use super::Grid2D;
+
+/// A 24-bit color, displayed as an `#rrggbb` hex code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// How [`Grid2D::write_svg`] turns cells into shapes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SvgShapes {
+    /// One square per cell.
+    Cells,
+    /// One rectangle per horizontal run of cells with the same color, which keeps large grids
+    /// down to a manageable file size.
+    Runs,
+}
+
+impl<T> Grid2D<T> {
+    /// Draws the grid as RGB pixels, row by row, with each cell covering a `scale` pixel square.
+    pub fn rasterize(&self, scale: usize, color: impl Fn(&T) -> Rgb) -> Vec<u8> {
+        assert!(
+            scale > 0,
+            "images must be drawn at least one pixel per cell"
+        );
+
+        let mut pixels = Vec::with_capacity(self.width * self.height * scale * scale * 3);
+        for y in 0..self.height {
+            let line = self
+                .row(y)
+                .iter()
+                .flat_map(|cell| {
+                    let Rgb(r, g, b) = color(cell);
+                    [r, g, b].repeat(scale)
+                })
+                .collect::<Vec<_>>();
+
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        pixels
+    }
+
+    /// Writes the grid as a binary PPM image.
+    pub fn write_ppm(
+        &self,
+        out: impl Write,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        let pixels = self.rasterize(scale, color);
+        write_ppm(out, self.width * scale, self.height * scale, &pixels)
+    }
+
+    /// Writes the grid as a PNG image.
+    pub fn write_png(
+        &self,
+        out: impl Write,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        let pixels = self.rasterize(scale, color);
+        let mut encoder = png::Encoder::new(
+            out,
+            (self.width * scale) as u32,
+            (self.height * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&pixels).map_err(io::Error::other)
+    }
+
+    /// Writes the grid as an SVG image made of filled rectangles.
+    pub fn write_svg(
+        &self,
+        mut out: impl Write,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+        shapes: SvgShapes,
+    ) -> io::Result<()> {
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" shape-rendering="crispEdges">"#,
+            self.width * scale,
+            self.height * scale
+        )?;
+
+        for y in 0..self.height {
+            let colors = self.row(y).iter().map(&color);
+            let runs: Vec<(usize, Rgb)> = match shapes {
+                SvgShapes::Cells => colors.map(|fill| (1, fill)).collect(),
+                SvgShapes::Runs => colors.fold(vec![], |mut runs: Vec<(usize, Rgb)>, fill| {
+                    match runs.last_mut() {
+                        Some((len, last)) if *last == fill => *len += 1,
+                        _ => runs.push((1, fill)),
+                    }
+                    runs
+                }),
+            };
+
+            let mut x = 0;
+            for (len, fill) in runs {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="{scale}" fill="{fill}"/>"#,
+                    x * scale,
+                    y * scale,
+                    len * scale
+                )?;
+                x += len;
+            }
+        }
+
+        writeln!(out, "</svg>")
+    }
+
+    /// Saves the grid as an image, picking PPM, PNG or SVG from the extension of `path`.
+    ///
+    /// SVGs are written with merged runs.
+    pub fn save_image(
+        &self,
+        path: impl AsRef<Path>,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        if !matches!(extension, Some("ppm" | "png" | "svg")) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("can't tell what format to save {} in", path.display()),
+            ));
+        }
+
+        let mut out = BufWriter::new(File::create(path)?);
+        match extension {
+            Some("ppm") => self.write_ppm(&mut out, scale, color)?,
+            Some("png") => self.write_png(&mut out, scale, color)?,
+            _ => self.write_svg(&mut out, scale, color, SvgShapes::Runs)?,
+        }
+
+        out.flush()
+    }
+}
+
+/// Writes `width * height` RGB pixels as a binary PPM image.
+pub fn write_ppm(
+    mut out: impl Write,
+    width: usize,
+    height: usize,
+    pixels: &[u8],
+) -> io::Result<()> {
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    out.write_all(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::image::*;
+
+    fn checkerboard() -> Grid2D<char> {
+        Grid2D::from_lines("#.\n##".lines())
+    }
+
+    fn color(&ch: &char) -> Rgb {
+        if ch == '#' {
+            Rgb(255, 0, 0)
+        } else {
+            Rgb(0, 0, 255)
+        }
+    }
+
+    #[test]
+    fn it_writes_scaled_ppms() {
+        let mut ppm = vec![];
+        checkerboard().write_ppm(&mut ppm, 2, color).unwrap();
+
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(header, &ppm[..header.len()]);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(4 * 4 * 3, pixels.len());
+        // the second row of pixels is still the first row of cells
+        assert_eq!(
+            &[255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255],
+            &pixels[12..24]
+        );
+    }
+
+    #[test]
+    fn it_writes_svgs_by_cell_or_by_run() {
+        let svg = |shapes| {
+            let mut out = vec![];
+            checkerboard()
+                .write_svg(&mut out, 10, color, shapes)
+                .unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        let cells = svg(SvgShapes::Cells);
+        assert_eq!(4, cells.matches("<rect").count());
+        assert!(cells.contains(r##"<rect x="10" y="0" width="10" height="10" fill="#0000ff"/>"##));
+
+        let runs = svg(SvgShapes::Runs);
+        assert_eq!(3, runs.matches("<rect").count());
+        assert!(runs.contains(r##"<rect x="0" y="10" width="20" height="10" fill="#ff0000"/>"##));
+        assert!(runs.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,401 @@
+// day5 only needs PiecewiseMap, so most of IntervalSet has no caller yet
+#![allow(dead_code)]
+
+use std::fmt::Display;
+
+use super::math::Integer;
+
+/// The values from `start` up to but not including `end`, which is empty when `end` isn't past
+/// `start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Display for Interval<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl<T> Interval<T>
+where
+    T: Integer,
+{
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The `len` values starting at `start`.
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether the intervals have a value in common, so touching intervals don't overlap.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The values in both intervals, which may be empty.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Moves the interval so that it starts at `start`, keeping its length.
+    fn moved_to(&self, start: T) -> Self {
+        Self::with_len(start, self.len())
+    }
+}
+
+/// A set of values stored as the fewest sorted intervals that cover them, so overlapping and
+/// touching intervals are merged as they're added and empty ones are dropped.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for IntervalSet<T>
+where
+    T: Integer,
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self {
+            intervals: normalize(iter.into_iter().collect()),
+        }
+    }
+}
+
+impl<T> From<Interval<T>> for IntervalSet<T>
+where
+    T: Integer,
+{
+    fn from(interval: Interval<T>) -> Self {
+        Self::from_iter([interval])
+    }
+}
+
+/// Sorts `intervals` and merges any that overlap or touch, dropping empty ones.
+pub fn normalize<T: Integer>(mut intervals: Vec<Interval<T>>) -> Vec<Interval<T>> {
+    intervals.retain(|interval| !interval.is_empty());
+    intervals.sort_unstable();
+
+    let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => merged.push(interval),
+        }
+    }
+
+    merged
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Integer,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The intervals making up the set, in order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, interval| total + interval.len())
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        self.intervals.push(interval);
+        self.intervals = normalize(std::mem::take(&mut self.intervals));
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = vec![];
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let overlap = a.intersection(b);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+
+            // whichever ends first can't overlap anything further along the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values in this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut j = 0;
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            // skip past the intervals of other that end before this one starts
+            while other.intervals.get(j).is_some_and(|b| b.end <= rest.start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            while let Some(b) = other.intervals.get(k).filter(|b| b.start < rest.end) {
+                if b.start > rest.start {
+                    intervals.push(Interval::new(rest.start, b.start));
+                }
+                rest.start = rest.start.max(b.end);
+                k += 1;
+            }
+
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+
+        Self { intervals }
+    }
+}
+
+/// A mapping that moves values within some source intervals by a fixed offset each, leaving
+/// every other value where it is, such as the almanac maps in 2023 day 5.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PiecewiseMap<T> {
+    /// The source intervals, sorted and disjoint, along with where each one starts once mapped.
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        Self { pieces: vec![] }
+    }
+}
+
+impl<T> PiecewiseMap<T>
+where
+    T: Integer,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The source intervals along with where each one starts once mapped, in order.
+    pub fn pieces(&self) -> &[(Interval<T>, T)] {
+        &self.pieces
+    }
+
+    /// Maps the values in `source` to the same number of values starting at `target`, panicking
+    /// if `source` overlaps a source that's already mapped.
+    pub fn insert(&mut self, source: Interval<T>, target: T) {
+        if source.is_empty() {
+            return;
+        }
+
+        let idx = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end <= source.start);
+        if let Some((piece, _)) = self
+            .pieces
+            .get(idx)
+            .filter(|(piece, _)| piece.overlaps(&source))
+        {
+            panic!("{source:?} overlaps the already mapped {piece:?}");
+        }
+
+        self.pieces.insert(idx, (source, target));
+    }
+
+    /// Where `value` ends up.
+    pub fn get(&self, value: T) -> T {
+        let idx = self.pieces.partition_point(|(piece, _)| piece.end <= value);
+        match self.pieces.get(idx) {
+            Some(&(piece, target)) if piece.contains(value) => value - piece.start + target,
+            _ => value,
+        }
+    }
+
+    fn sources(&self) -> IntervalSet<T> {
+        self.pieces.iter().map(|&(piece, _)| piece).collect()
+    }
+
+    /// Where every value in `set` ends up.
+    pub fn apply(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut mapped = set.difference(&self.sources()).intervals;
+        for &(piece, target) in &self.pieces {
+            for interval in &set.intervals {
+                let overlap = interval.intersection(&piece);
+                if !overlap.is_empty() {
+                    mapped.push(overlap.moved_to(overlap.start - piece.start + target));
+                }
+            }
+        }
+
+        mapped.into_iter().collect()
+    }
+
+    /// The map that sends each value to where `next` puts it after this map has been applied.
+    pub fn compose(&self, next: &Self) -> Self {
+        let next_sources = next.sources();
+        let mut pieces = vec![];
+        for &(piece, target) in &self.pieces {
+            // split where this piece lands by the pieces of next it lands in
+            let image = piece.moved_to(target);
+            let split = next
+                .pieces
+                .iter()
+                .map(|(next_piece, _)| image.intersection(next_piece))
+                .filter(|overlap| !overlap.is_empty())
+                .chain(IntervalSet::from(image).difference(&next_sources).intervals);
+            for part in split {
+                let source = part.moved_to(part.start - target + piece.start);
+                pieces.push((source, next.get(part.start)));
+            }
+        }
+
+        // values this map leaves alone go wherever next puts them
+        let sources = self.sources();
+        for &(next_piece, _) in &next.pieces {
+            for part in IntervalSet::from(next_piece).difference(&sources).intervals {
+                pieces.push((part, next.get(part.start)));
+            }
+        }
+
+        pieces.retain(|&(source, target)| source.start != target);
+        pieces.sort_unstable();
+        Self { pieces }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::interval::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn it_normalizes_interval_sets() {
+        let a = set(&[(5, 8), (0, 3), (3, 4), (7, 10), (20, 20)]);
+        assert_eq!(&[Interval::new(0, 4), Interval::new(5, 10)], a.intervals());
+        assert_eq!(9, a.len());
+        assert!(a.contains(9) && !a.contains(4) && !a.contains(10));
+        assert_eq!(0, Interval::new(4, 2).len());
+
+        let b = set(&[(2, 6), (9, 12)]);
+        assert_eq!(set(&[(0, 12)]), a.union(&b));
+        assert_eq!(set(&[(2, 4), (5, 6), (9, 10)]), a.intersection(&b));
+        assert_eq!(set(&[(0, 2), (6, 9)]), a.difference(&b));
+        assert_eq!(set(&[(4, 5), (10, 12)]), b.difference(&a));
+    }
+
+    #[test]
+    fn it_maps_intervals_piecewise() {
+        let mut map = PiecewiseMap::new();
+        map.insert(Interval::with_len(10_u64, 5), 20);
+        map.insert(Interval::with_len(30, 5), 0);
+        assert_eq!(
+            (9, 22, 2, 35),
+            (map.get(9), map.get(12), map.get(32), map.get(35))
+        );
+
+        // 5..40 hangs off either side of both pieces, and 30..35 lands next to 5..10
+        let seeds = IntervalSet::from(Interval::new(5, 40));
+        assert_eq!(
+            [(0, 10), (15, 30), (35, 40)]
+                .map(|(start, end)| Interval::new(start, end))
+                .as_slice(),
+            map.apply(&seeds).intervals()
+        );
+    }
+
+    #[test]
+    fn it_composes_maps() {
+        let mut first = PiecewiseMap::new();
+        first.insert(Interval::new(0, 10), 100);
+        first.insert(Interval::new(20, 30), 5);
+        let mut second = PiecewiseMap::new();
+        second.insert(Interval::new(105, 110), 0);
+        second.insert(Interval::new(0, 25), 50);
+
+        let both = first.compose(&second);
+        for value in -5..120 {
+            assert_eq!(second.get(first.get(value)), both.get(value), "{value}");
+        }
+
+        let values = IntervalSet::from(Interval::new(-5, 120));
+        assert_eq!(second.apply(&first.apply(&values)), both.apply(&values));
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps")]
+    fn it_rejects_overlapping_pieces() {
+        let mut map = PiecewiseMap::new();
+        map.insert(Interval::new(0, 10), 100);
+        map.insert(Interval::new(9, 12), 0);
+    }
+}
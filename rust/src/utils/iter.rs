@@ -0,0 +1,155 @@
+use super::{Grid2D, GridView, Point};
+
+impl<T> Grid2D<T> {
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions()
+            .zip((0..self.height).flat_map(|y| self.row(y)))
+    }
+
+    /// Every cell along with its position, row by row, allowing the cells to be changed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let (width, height) = (self.width, self.height);
+        self.cells
+            .chunks_mut(self.stride)
+            .take(height)
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row[..width]
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(x, cell)| (Point::new(x, y), cell))
+            })
+    }
+
+    /// The position of the first cell matching `predicate`, reading row by row.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The positions of every cell matching `predicate`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Every `size` by `size` block of cells that fits in the grid, along with the position of its
+    /// top left corner, row by row.
+    ///
+    /// Yields nothing if the grid is smaller than a single window.
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = (Point, GridView<'_, T>)> {
+        assert!(size > 0, "windows must be at least one cell across");
+        let (xs, ys) = (
+            (self.width + 1).saturating_sub(size),
+            (self.height + 1).saturating_sub(size),
+        );
+        (0..ys)
+            .flat_map(move |y| (0..xs).map(move |x| Point::new(x, y)))
+            .map(move |pos| (pos, self.sub_grid(pos.x, pos.y, size, size)))
+    }
+
+    /// Returns row `y` as a mutable slice, panicking if it's out of bounds.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "row {y} is out of bounds");
+        let start = y * self.stride;
+        &mut self.cells[start..start + self.width]
+    }
+
+    /// Iterates over row `row_index`, allowing its cells to be changed, and yielding nothing if
+    /// it's out of bounds.
+    pub fn row_iterator_mut(&mut self, row_index: usize) -> impl Iterator<Item = &mut T> {
+        let cells = if row_index < self.height {
+            self.row_mut(row_index)
+        } else {
+            &mut []
+        };
+
+        cells.iter_mut()
+    }
+
+    /// Iterates down column `column_index`, allowing its cells to be changed, and yielding
+    /// nothing if it's out of bounds.
+    pub fn col_iterator_mut(&mut self, column_index: usize) -> impl Iterator<Item = &mut T> {
+        let remaining = if column_index < self.width {
+            self.height
+        } else {
+            0
+        };
+
+        self.cells
+            .iter_mut()
+            .skip(column_index)
+            .step_by(self.stride.max(1))
+            .take(remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+
+    fn grid() -> Grid2D<char> {
+        Grid2D::from_lines("abc\ndef\nghi".lines())
+    }
+
+    #[test]
+    fn it_iterates_with_positions() {
+        let mut grid = grid();
+        let (pos, &ch) = grid.iter().nth(5).unwrap();
+        assert_eq!((Point::new(2, 1), 'f'), (pos, ch));
+        assert_eq!(9, grid.positions().count());
+        assert_eq!(Some(Point::new(1, 2)), grid.find(|&ch| ch == 'h'));
+        assert_eq!(None, grid.find(|&ch| ch == 'z'));
+
+        let vowels = grid
+            .find_all(|ch| "aeiou".contains(*ch))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)],
+            vowels
+        );
+
+        for (pos, ch) in grid.iter_mut() {
+            if pos.x == pos.y {
+                *ch = '*';
+            }
+        }
+        assert_eq!("*bc\nd*f\ngh*", grid.to_string());
+    }
+
+    #[test]
+    fn it_slides_windows_over_the_grid() {
+        let grid = grid();
+        let windows = grid.windows(2).collect::<Vec<_>>();
+        assert_eq!(4, windows.len());
+        assert_eq!(Point::new(1, 1), windows[3].0);
+        assert_eq!("ef\nhi\n", windows[3].1.to_string());
+
+        assert_eq!(9, grid.windows(1).count());
+        assert_eq!(0, grid.windows(4).count());
+    }
+
+    #[test]
+    fn it_changes_rows_and_columns_in_place() {
+        let mut grid = grid();
+        grid.row_mut(0).reverse();
+        for ch in grid.col_iterator_mut(1) {
+            *ch = ch.to_ascii_uppercase();
+        }
+        grid.row_iterator_mut(2).for_each(|ch| *ch = '.');
+        assert_eq!(0, grid.col_iterator_mut(3).count());
+        assert_eq!("cBa\ndEf\n...", grid.to_string());
+    }
+}
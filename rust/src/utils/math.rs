@@ -0,0 +1,279 @@
+// the modular arithmetic and checked helpers are waiting on their first puzzle
+#![allow(dead_code)]
+
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The primitive integer types, so the helpers below work the same on anything from `u8` to
+/// `i128`.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The distance from zero, which is the value itself for unsigned types.
+    fn magnitude(self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// The square root rounded down, or `None` for negative values.
+    fn checked_isqrt(self) -> Option<Self>;
+    /// The nearest `f64`, which loses precision for values beyond 2^53.
+    fn to_f64(self) -> f64;
+}
+
+/// The integer types that can go negative, which is needed for Bézout coefficients and modular
+/// arithmetic.
+pub trait Signed: Integer + Neg<Output = Self> {
+    /// The remainder of dividing by `rhs`, always between zero and `rhs`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    (unsigned: $($t:ty),*) => {
+        $(
+            impl_integer!($t, self => self, self => Some(self.isqrt()));
+        )*
+    };
+    (signed: $($t:ty),*) => {
+        $(
+            impl_integer!($t, self => self.abs(), self => (self >= 0).then(|| self.isqrt()));
+
+            impl Signed for $t {
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+    ($t:ty, $s1:ident => $magnitude:expr, $s2:ident => $isqrt:expr) => {
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn magnitude($s1) -> Self {
+                $magnitude
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_isqrt($s2) -> Option<Self> {
+                $isqrt
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    };
+}
+
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+
+/// The greatest common divisor of `a` and `b`, which is never negative. `gcd(0, 0)` is zero.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.magnitude(), b.magnitude());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple of `a` and `b`, panicking if it overflows.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm of {a:?} and {b:?} overflowed"))
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it overflows.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (a.magnitude() / gcd(a, b)).checked_mul(b.magnitude())
+}
+
+/// The least common multiple of every value, which is one if there aren't any, panicking if it
+/// overflows.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ONE, lcm)
+}
+
+/// The least common multiple of every value, or `None` if it overflows.
+pub fn checked_lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, checked_lcm)
+}
+
+/// Finds `(g, x, y)` such that `a * x + b * y == g`, where `g` is the greatest common divisor of
+/// `a` and `b`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` between zero and `modulus` for which `a * x` is one more than a multiple of
+/// `modulus`, or `None` if `a` and `modulus` share a factor.
+pub fn mod_inverse<T: Signed>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// Why [`crt`] couldn't solve a set of congruences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrtError {
+    /// Two of the congruences contradict each other.
+    NoSolution,
+    /// The combined modulus, or a step towards it, doesn't fit in the integer type.
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSolution => f.write_str("the congruences contradict each other"),
+            Self::Overflow => f.write_str("the combined modulus overflowed"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, returning the smallest
+/// non-negative `x` along with the modulus that all the solutions repeat over.
+///
+/// Moduli don't need to be coprime, and must all be positive.
+pub fn crt<T: Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Result<(T, T), CrtError> {
+    let mut solution = (T::ZERO, T::ONE);
+    for (residue, modulus) in congruences {
+        assert!(
+            modulus > T::ZERO,
+            "moduli must be positive, not {modulus:?}"
+        );
+        solution = merge_congruences(solution, (residue.rem_euclid(modulus), modulus))?;
+    }
+
+    Ok(solution)
+}
+
+/// Combines two congruences into the one that both hold under.
+fn merge_congruences<T: Signed>((r1, m1): (T, T), (r2, m2): (T, T)) -> Result<(T, T), CrtError> {
+    let g = gcd(m1, m2);
+    let diff = r2.checked_sub(r1).ok_or(CrtError::Overflow)?;
+    if diff % g != T::ZERO {
+        return Err(CrtError::NoSolution);
+    }
+
+    // step r1 along in multiples of m1 until it also lands on r2 modulo m2
+    let step_modulus = m2 / g;
+    let inverse = mod_inverse(m1 / g, step_modulus).expect("m1 / g and m2 / g are coprime");
+    let steps = (diff / g)
+        .rem_euclid(step_modulus)
+        .checked_mul(inverse)
+        .ok_or(CrtError::Overflow)?
+        .rem_euclid(step_modulus);
+
+    let modulus = (m1 / g).checked_mul(m2).ok_or(CrtError::Overflow)?;
+    let residue = m1
+        .checked_mul(steps)
+        .and_then(|offset| r1.checked_add(offset))
+        .ok_or(CrtError::Overflow)?;
+    Ok((residue.rem_euclid(modulus), modulus))
+}
+
+/// The square root of `n` rounded down, panicking if `n` is negative.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    n.checked_isqrt()
+        .unwrap_or_else(|| panic!("can't take the square root of {n:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::math::*;
+
+    #[test]
+    fn it_finds_divisors_and_multiples() {
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(6, gcd(-12_i64, 18));
+        assert_eq!(5, gcd(0_u8, 5));
+        assert_eq!(36, lcm(12, 18));
+        assert_eq!(0, lcm(0, 7));
+        assert_eq!(2520, lcm_all(1..=10_u64));
+        assert_eq!(1, lcm_all(Vec::<u32>::new()));
+        assert_eq!(None, checked_lcm_all([255_u8, 254]));
+        assert_eq!(
+            Some(1_u128 << 100),
+            checked_lcm_all([1_u128 << 100, 1 << 64])
+        );
+    }
+
+    #[test]
+    fn it_does_modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3_i128, 11));
+        assert_eq!(None, mod_inverse(6, 9));
+    }
+
+    #[test]
+    fn it_solves_congruences() {
+        assert_eq!(Ok((23, 105)), crt([(2, 3), (3, 5), (2, 7)]));
+        // the moduli share a factor of 2, and agree on it
+        assert_eq!(Ok((10, 12)), crt([(2, 4), (4, 6)]));
+        assert_eq!(Err(CrtError::NoSolution), crt([(1, 4), (2, 6)]));
+        assert_eq!(
+            Err(CrtError::Overflow),
+            crt([(1, i64::MAX), (0, i64::MAX - 1)])
+        );
+        assert_eq!(
+            Ok((1, i64::MAX as i128 * (i64::MAX as i128 - 1))),
+            crt([(1, i64::MAX as i128), (1, i64::MAX as i128 - 1)])
+        );
+        assert_eq!(Ok((0, 1)), crt(Vec::<(i32, i32)>::new()));
+    }
+
+    #[test]
+    fn it_takes_integer_square_roots() {
+        assert_eq!(3, isqrt(15));
+        assert_eq!(4, isqrt(16_u8));
+        assert_eq!(3_037_000_499, isqrt(i64::MAX));
+        assert_eq!(None, (-1_i32).checked_isqrt());
+    }
+}
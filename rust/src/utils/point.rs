@@ -0,0 +1,150 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a grid, measured in cells from the top left corner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves by `delta`, returning `None` if that would leave the positive quadrant.
+    pub fn checked_add(self, delta: Vec2) -> Option<Self> {
+        let x = self.x.checked_add_signed(delta.x.try_into().ok()?)?;
+        let y = self.y.checked_add_signed(delta.y.try_into().ok()?)?;
+        Some(Self { x, y })
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    /// The offset that takes `rhs` to `self`.
+    fn sub(self, rhs: Self) -> Vec2 {
+        Vec2::new(self.x as i64 - rhs.x as i64, self.y as i64 - rhs.y as i64)
+    }
+}
+
+impl Add<Vec2> for Point {
+    type Output = Self;
+
+    /// Moves by `rhs`, panicking if that would leave the positive quadrant.
+    fn add(self, rhs: Vec2) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| panic!("{self:?} + {rhs:?} is off the grid"))
+    }
+}
+
+impl AddAssign<Vec2> for Point {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// A signed offset between two points, with `y` growing downwards as it does on a grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_len(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::point::*;
+
+    #[test]
+    fn it_moves_points_by_offsets() {
+        let point = Point::new(2, 1);
+        assert_eq!(Some(Point::new(5, 0)), point.checked_add(Vec2::new(3, -1)));
+        assert_eq!(None, point.checked_add(Vec2::new(0, -2)));
+        assert_eq!(Point::new(5, 0), point + Vec2::new(3, -1));
+        let mut moved = point;
+        moved += Vec2::new(-2, 1);
+        assert_eq!(Point::new(0, 2), moved);
+        assert_eq!(Vec2::new(-2, -1), Point::new(0, 0) - point);
+        assert_eq!(3, point.manhattan_distance(Point::new(0, 0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_on_moving_off_the_grid() {
+        let _ = Point::new(2, 1) + Vec2::new(-3, 0);
+    }
+
+    #[test]
+    fn it_does_vector_arithmetic() {
+        let a = Vec2::new(1, -2);
+        assert_eq!(Vec2::new(3, -6), a * 3);
+        assert_eq!(Vec2::new(0, 0), a + -a);
+        assert_eq!(Vec2::new(2, -4), a - -a);
+        assert_eq!(3, a.manhattan_len());
+    }
+}
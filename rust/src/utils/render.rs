@@ -0,0 +1,163 @@
+// masks and forcing colors on or off are only used by tests so far
+#![allow(dead_code)]
+
+use std::{
+    fmt::{Display, Write},
+    io::{self, IsTerminal},
+};
+
+use crossterm::{
+    style::{ResetColor, SetForegroundColor},
+    Command,
+};
+
+pub use crossterm::style::Color;
+
+use super::{Grid2D, Point};
+
+/// Draws a grid one character per cell with some of its cells picked out in color, as returned
+/// by [`Grid2D::highlighted`].
+pub struct Highlighted<'a, T, F> {
+    grid: &'a Grid2D<T>,
+    to_char: F,
+    colors: Grid2D<Option<Color>>,
+    ansi: bool,
+}
+
+impl<T> Grid2D<T> {
+    /// Displays the grid like [`Grid2D::display_with`], with room to color in layers of cells.
+    ///
+    /// Colors are only written when stdout is a terminal, so output that is piped or redirected
+    /// stays plain text.
+    pub fn highlighted<F>(&self, to_char: F) -> Highlighted<'_, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        Highlighted {
+            grid: self,
+            to_char,
+            colors: Grid2D::with_size(self.width, self.height, None),
+            ansi: io::stdout().is_terminal(),
+        }
+    }
+}
+
+impl<T, F> Highlighted<'_, T, F> {
+    /// Colors each of `points`, drawing over any earlier layer. Points outside the grid are
+    /// ignored.
+    pub fn layer(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        for pos in points {
+            if let Some(cell) = self.colors.get_mut(pos.x, pos.y) {
+                *cell = Some(color);
+            }
+        }
+
+        self
+    }
+
+    /// Colors every cell that is set in `mask`, drawing over any earlier layer.
+    pub fn mask(mut self, mask: &Grid2D<bool>, color: Color) -> Self {
+        for y in 0..mask.height.min(self.colors.height) {
+            for (x, _) in mask.row(y).iter().enumerate().filter(|(_, &set)| set) {
+                if let Some(cell) = self.colors.get_mut(x, y) {
+                    *cell = Some(color);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Forces colors on or off, whatever stdout is connected to.
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+}
+
+impl<T, F> Display for Highlighted<'_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.grid.height {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+
+            // only switch colors where a run of same colored cells starts or ends
+            let mut current = None;
+            for (item, &color) in self.grid.row(y).iter().zip(self.colors.row(y)) {
+                if self.ansi && color != current {
+                    match color {
+                        Some(color) => SetForegroundColor(color).write_ansi(f)?,
+                        None => ResetColor.write_ansi(f)?,
+                    }
+                    current = color;
+                }
+
+                f.write_char((self.to_char)(item))?;
+            }
+
+            if current.is_some() {
+                ResetColor.write_ansi(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Swaps the ASCII pipe characters used by puzzle inputs (`|-LJ7F`) for their box-drawing
+/// equivalents, leaving anything else as it is.
+pub fn box_drawing(ch: char) -> char {
+    match ch {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        _ => ch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::render::*;
+
+    fn pipes() -> Grid2D<char> {
+        Grid2D::from_lines("F-7\n|.|\nL-J".lines())
+    }
+
+    #[test]
+    fn it_draws_plain_text_without_ansi() {
+        let grid = pipes();
+        let rendered = grid
+            .highlighted(|&ch| box_drawing(ch))
+            .layer([Point::new(1, 1)], Color::Red)
+            .ansi(false);
+        assert_eq!("┌─┐\n│.│\n└─┘", rendered.to_string());
+    }
+
+    #[test]
+    fn it_colors_runs_of_highlighted_cells() {
+        let grid = pipes();
+        let walls = grid.map(|&ch| ch != '.');
+        let rendered = grid
+            .highlighted(|&ch| ch)
+            .mask(&walls, Color::Blue)
+            .layer([Point::new(2, 0), Point::new(5, 5)], Color::Red)
+            .ansi(true)
+            .to_string();
+
+        let (blue, red) = ("\x1b[38;5;12m", "\x1b[38;5;9m");
+        let reset = "\x1b[0m";
+        let first_row = format!("{blue}F-{red}7{reset}");
+        assert_eq!(Some(first_row.as_str()), rendered.lines().next());
+        assert_eq!(
+            format!("{blue}|{reset}.{blue}|{reset}"),
+            rendered.lines().nth(1).unwrap()
+        );
+    }
+}
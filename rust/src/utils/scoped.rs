@@ -0,0 +1,60 @@
+use std::{cell::RefCell, thread::LocalKey};
+
+/// A thread-local slot that a runner fills in for the duration of a call, such as the progress
+/// reporter or frame recorder that solvers send updates to.
+pub type Slot<H> = LocalKey<RefCell<Option<H>>>;
+
+/// Runs `f` with `hook` in `slot`, handing back the result along with whatever hook is in the
+/// slot once `f` returns.
+///
+/// Whichever hook was in the slot before is put back afterwards, even if `f` panics.
+pub fn with_hook<H: 'static, T>(
+    slot: &'static Slot<H>,
+    hook: H,
+    f: impl FnOnce() -> T,
+) -> (T, Option<H>) {
+    struct Restore<H: 'static> {
+        slot: &'static Slot<H>,
+        previous: Option<H>,
+    }
+
+    impl<H> Drop for Restore<H> {
+        fn drop(&mut self) {
+            let previous = self.previous.take();
+            self.slot.with(|s| *s.borrow_mut() = previous);
+        }
+    }
+
+    let previous = slot.with(|s| s.borrow_mut().replace(hook));
+    let restore = Restore { slot, previous };
+    let result = f();
+    let hook = slot.with(|s| s.borrow_mut().take());
+    drop(restore);
+    (result, hook)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use crate::utils::scoped::*;
+
+    thread_local! {
+        static SLOT: RefCell<Option<u32>> = const { RefCell::new(None) };
+    }
+
+    #[test]
+    fn it_restores_the_previous_hook() {
+        let (inner, hook) = with_hook(&SLOT, 1, || {
+            let (inner, hook) = with_hook(&SLOT, 2, || SLOT.with(|s| *s.borrow()));
+            assert_eq!(Some(2), hook);
+            inner
+        });
+        assert_eq!((Some(2), Some(1)), (inner, hook));
+        assert_eq!(None, SLOT.with(|s| *s.borrow()));
+
+        let result = panic::catch_unwind(|| with_hook(&SLOT, 3, || panic!("solver failed")));
+        assert!(result.is_err());
+        assert_eq!(None, SLOT.with(|s| *s.borrow()));
+    }
+}
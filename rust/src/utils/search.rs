@@ -0,0 +1,340 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use super::{
+    addressing::{Addressed, Addressing},
+    Grid2D, Point,
+};
+
+/// The cheapest route found by a search, from the start state to the goal inclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Every state a search has reached, stored once and referred to by index so that states only
+/// need to be cloned on discovery.
+struct Visited<S, C> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<C>,
+}
+
+impl<S, C> Visited<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            states: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            parents: vec![None],
+            costs: vec![cost],
+        }
+    }
+
+    /// Records reaching `state` from `parent` at `cost`, returning its index if that's the first
+    /// or cheapest way found so far.
+    fn reach(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        match self.indices.entry(state) {
+            Entry::Vacant(entry) => {
+                let idx = self.states.len();
+                self.states.push(entry.key().clone());
+                entry.insert(idx);
+                self.parents.push(Some(parent));
+                self.costs.push(cost);
+                Some(idx)
+            }
+            Entry::Occupied(entry) => {
+                let idx = *entry.get();
+                if cost >= self.costs[idx] {
+                    return None;
+                }
+
+                self.parents[idx] = Some(parent);
+                self.costs[idx] = cost;
+                Some(idx)
+            }
+        }
+    }
+
+    fn path_to(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![];
+        let mut current = Some(goal);
+        while let Some(idx) = current {
+            states.push(self.states[idx].clone());
+            current = self.parents[idx];
+        }
+
+        states.reverse();
+        Path {
+            cost: self.costs[goal],
+            states,
+        }
+    }
+}
+
+/// Finds the route from `start` to a goal state with the fewest steps, or `None` if no goal can
+/// be reached.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut frontier = VecDeque::from([0]);
+    while let Some(current) = frontier.pop_front() {
+        let state = visited.states[current].clone();
+        if is_goal(&state) {
+            return Some(visited.path_to(current));
+        }
+
+        let cost = visited.costs[current] + 1;
+        for next in successors(&state) {
+            // the first time a state is reached is always the fewest steps
+            if visited.indices.contains_key(&next) {
+                continue;
+            }
+
+            if let Some(idx) = visited.reach(next, current, cost) {
+                frontier.push_back(idx);
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest route from `start` to a goal state, where `successors` returns each next
+/// state along with the cost of moving to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores the states that `heuristic` estimates are closest to a goal
+/// first.
+///
+/// The heuristic must never overestimate the remaining cost, or the route found may not be the
+/// cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start, C::default());
+    while let Some(Reverse((_, cost, current))) = frontier.pop() {
+        // skip entries left behind when a cheaper route to the same state was found
+        if cost > visited.costs[current] {
+            continue;
+        }
+
+        let state = visited.states[current].clone();
+        if is_goal(&state) {
+            return Some(visited.path_to(current));
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if let Some(idx) = visited.reach(next, current, next_cost) {
+                let estimate = next_cost + heuristic(&visited.states[idx]);
+                frontier.push(Reverse((estimate, next_cost, idx)));
+            }
+        }
+    }
+
+    None
+}
+
+impl<T> Grid2D<T> {
+    /// Finds the fewest orthogonal steps from `start` to `goal`, only moving onto cells that are
+    /// `passable`.
+    pub fn bfs(
+        &self,
+        start: Point,
+        goal: Point,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Path<Point, usize>> {
+        self.addressed(Addressing::Bounded)
+            .bfs(start, goal, passable)
+    }
+
+    /// Finds the cheapest orthogonal route from `start` to `goal`, where moving onto a cell costs
+    /// `cost(cell)`, or is impossible if that's `None`.
+    pub fn dijkstra<C>(
+        &self,
+        start: Point,
+        goal: Point,
+        cost: impl Fn(&T) -> Option<C>,
+    ) -> Option<Path<Point, C>>
+    where
+        C: Copy + Ord + Add<Output = C> + Default,
+    {
+        self.addressed(Addressing::Bounded)
+            .dijkstra(start, goal, cost)
+    }
+}
+
+impl<T> Addressed<'_, T> {
+    /// Like [`Grid2D::bfs`], stepping between neighbors under the view's addressing mode.
+    pub fn bfs(
+        &self,
+        start: Point,
+        goal: Point,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Path<Point, usize>> {
+        let grid = self.grid();
+        bfs(
+            start,
+            |&pos| {
+                self.neighbors4(pos)
+                    .filter(|next| grid.get(next.x, next.y).is_some_and(&passable))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == goal,
+        )
+    }
+
+    /// Like [`Grid2D::dijkstra`], stepping between neighbors under the view's addressing mode.
+    pub fn dijkstra<C>(
+        &self,
+        start: Point,
+        goal: Point,
+        cost: impl Fn(&T) -> Option<C>,
+    ) -> Option<Path<Point, C>>
+    where
+        C: Copy + Ord + Add<Output = C> + Default,
+    {
+        let grid = self.grid();
+        dijkstra(
+            start,
+            |&pos| {
+                self.neighbors4(pos)
+                    .filter_map(|next| Some((next, cost(grid.get(next.x, next.y)?)?)))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == goal,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::utils::{addressing::Addressing, search::*};
+
+    fn maze() -> Grid2D<char> {
+        Grid2D::from_lines("..#.\n#...\n..#.".lines())
+    }
+
+    #[test]
+    fn it_finds_the_fewest_steps() {
+        // count up from 1 to 10 by either adding one or doubling
+        let path = bfs(1, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+        assert_eq!(4, path.cost);
+        assert_eq!(vec![1, 2, 4, 5, 10], path.states);
+
+        let doubling = |&n: &u32| (n < 100).then_some(n * 2);
+        assert_eq!(None, bfs(1, doubling, |&n| n == 3));
+    }
+
+    #[test]
+    fn it_finds_the_cheapest_route() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('b', vec![('d', 1)]),
+            ('c', vec![('b', 3), ('d', 8)]),
+            ('d', vec![]),
+        ]);
+        let successors = |node: &char| edges[node].clone();
+        let path = dijkstra('a', successors, |&node| node == 'd').unwrap();
+        assert_eq!(6, path.cost);
+        assert_eq!(vec!['a', 'c', 'b', 'd'], path.states);
+
+        let path = astar('a', successors, |_| 0, |&node| node == 'd').unwrap();
+        assert_eq!(6, path.cost);
+    }
+
+    #[test]
+    fn it_expands_fewer_states_with_a_heuristic() {
+        // a wall across most of an open field, so the route has to go around its end
+        let mut field = Grid2D::with_size(20, 20, '.');
+        for y in 0..15 {
+            field.replace('#', 10, y);
+        }
+        let (start, goal) = (Point::new(0, 0), Point::new(19, 0));
+
+        let expanded = Cell::new(0);
+        let successors = |&point: &Point| {
+            expanded.set(expanded.get() + 1);
+            field
+                .neighbors4(point)
+                .filter(|next| field.get(next.x, next.y) == Some(&'.'))
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let cheapest = dijkstra(start, successors, |&point| point == goal).unwrap();
+        let dijkstra_expanded = expanded.replace(0);
+
+        let manhattan = |point: &Point| point.manhattan_distance(goal);
+        let guided = astar(start, successors, manhattan, |&point| point == goal).unwrap();
+        assert_eq!(49, cheapest.cost);
+        assert_eq!(cheapest.cost, guided.cost);
+        let astar_expanded = expanded.get();
+        assert!(
+            astar_expanded < dijkstra_expanded,
+            "astar expanded {astar_expanded} states, dijkstra {dijkstra_expanded}"
+        );
+    }
+
+    #[test]
+    fn it_searches_grids() {
+        let maze = maze();
+        let (start, goal) = (Point::new(0, 0), Point::new(3, 0));
+        let path = maze.bfs(start, goal, |&ch| ch == '.').unwrap();
+        assert_eq!(5, path.cost);
+        assert_eq!(Some(&goal), path.states.last());
+
+        let path = maze
+            .dijkstra(start, goal, |&ch| (ch == '.').then_some(2))
+            .unwrap();
+        assert_eq!(10, path.cost);
+        assert_eq!(None, maze.bfs(start, Point::new(3, 2), |&ch| ch == '#'));
+
+        // going off the left edge comes back in on the right
+        let torus = maze.addressed(Addressing::Wrapping);
+        let path = torus.bfs(start, goal, |&ch| ch == '.').unwrap();
+        assert_eq!(1, path.cost);
+        let path = torus
+            .dijkstra(start, goal, |&ch| (ch == '.').then_some(2))
+            .unwrap();
+        assert_eq!(2, path.cost);
+    }
+}
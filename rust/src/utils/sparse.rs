@@ -0,0 +1,271 @@
+// day18 only fills in and reads back its trench, so the rest has no caller yet
+#![allow(dead_code)]
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+};
+
+use super::Grid2D;
+
+/// An unbounded grid addressed by signed `(x, y)` coordinates, which only stores the cells that
+/// have been set. Every other cell reads as `default`.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    default: T,
+    bounds: Option<Bounds>,
+    /// The extent of the dense grid this was copied from, which the bounds never shrink inside.
+    frame: Option<Bounds>,
+}
+
+/// The smallest rectangle containing every stored cell of a [`SparseGrid`], along with the dense
+/// grid it was copied from if any, inclusive at both ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub max_x: i64,
+    pub min_y: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    fn around(x: i64, y: i64) -> Self {
+        Self {
+            min_x: x,
+            max_x: x,
+            min_y: y,
+            max_y: y,
+        }
+    }
+
+    fn include(&mut self, x: i64, y: i64) {
+        self.min_x = self.min_x.min(x);
+        self.max_x = self.max_x.max(x);
+        self.min_y = self.min_y.min(y);
+        self.max_y = self.max_y.max(y);
+    }
+
+    /// The smallest rectangle containing both `a` and `b`.
+    fn union(a: Option<Self>, b: Option<Self>) -> Option<Self> {
+        match (a, b) {
+            (Some(mut a), Some(b)) => {
+                a.include(b.min_x, b.min_y);
+                a.include(b.max_x, b.max_y);
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y + 1) as usize
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+            frame: None,
+        }
+    }
+
+    /// Returns the cell at `(x, y)`, or the default if it's never been set.
+    pub fn get(&self, x: i64, y: i64) -> &T {
+        self.cells.get(&(x, y)).unwrap_or(&self.default)
+    }
+
+    /// Whether `(x, y)` has been set, even if only to the default.
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    /// Sets the cell at `(x, y)`, returning whatever was stored there before.
+    pub fn insert(&mut self, x: i64, y: i64, item: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(x, y),
+            None => self.bounds = Some(Bounds::around(x, y)),
+        }
+
+        self.cells.insert((x, y), item)
+    }
+
+    /// Clears the cell at `(x, y)` back to the default, shrinking the bounds if need be.
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let removed = self.cells.remove(&(x, y))?;
+        self.bounds = None;
+        for &(x, y) in self.cells.keys() {
+            match &mut self.bounds {
+                Some(bounds) => bounds.include(x, y),
+                None => self.bounds = Some(Bounds::around(x, y)),
+            }
+        }
+
+        Some(removed)
+    }
+
+    /// The number of cells that have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The rectangle spanned by every cell that has been set and the dense grid this was copied
+    /// from, or `None` if there aren't any.
+    pub fn bounds(&self) -> Option<Bounds> {
+        Bounds::union(self.bounds, self.frame)
+    }
+
+    /// Iterates over the cells that have been set, top row first and left to right within a row.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        let mut positions = self.cells.keys().copied().collect::<Vec<_>>();
+        positions.sort_unstable_by_key(|&(x, y)| (y, x));
+        positions.into_iter().map(|pos| (pos, &self.cells[&pos]))
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Clone,
+{
+    /// Returns the cell at `(x, y)` for updating, setting it to the default first if need be.
+    pub fn get_mut(&mut self, x: i64, y: i64) -> &mut T {
+        if !self.contains(x, y) {
+            self.insert(x, y, self.default.clone());
+        }
+
+        self.cells.get_mut(&(x, y)).unwrap()
+    }
+
+    /// Copies the cells within the bounds into a dense grid, whose top left corner is the cell at
+    /// `(bounds.min_x, bounds.min_y)`.
+    pub fn to_grid(&self) -> Grid2D<T> {
+        let Some(bounds) = self.bounds() else {
+            return Grid2D::with_size(0, 0, self.default.clone());
+        };
+
+        let mut grid = Grid2D::with_size(bounds.width(), bounds.height(), self.default.clone());
+        for (&(x, y), item) in &self.cells {
+            let (x, y) = ((x - bounds.min_x) as usize, (y - bounds.min_y) as usize);
+            grid.replace(item.clone(), x, y);
+        }
+
+        grid
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Copies a dense grid, whose top left corner becomes `(0, 0)`, storing only the cells that
+    /// differ from `default`. The bounds still cover the whole of `grid`, so [`Self::to_grid`]
+    /// gives it back unchanged.
+    pub fn from_grid(grid: &Grid2D<T>, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        if grid.width() > 0 && grid.height() > 0 {
+            let mut frame = Bounds::around(0, 0);
+            frame.include(grid.width() as i64 - 1, grid.height() as i64 - 1);
+            sparse.frame = Some(frame);
+        }
+
+        for y in 0..grid.height() {
+            for (x, item) in grid.row(y).iter().enumerate() {
+                if *item != sparse.default {
+                    sparse.insert(x as i64, y as i64, item.clone());
+                }
+            }
+        }
+
+        sparse
+    }
+}
+
+impl<T> Display for SparseGrid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in bounds.min_y..=bounds.max_y {
+            for x in bounds.min_x..=bounds.max_x {
+                f.write_fmt(format_args!("{}", self.get(x, y)))?;
+            }
+
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+
+    #[test]
+    fn it_tracks_bounds_around_signed_cells() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert(-2, 1, '#');
+        grid.insert(1, -1, '#');
+        assert_eq!(
+            Some(Bounds {
+                min_x: -2,
+                max_x: 1,
+                min_y: -1,
+                max_y: 1
+            }),
+            grid.bounds()
+        );
+        assert_eq!("...#\n....\n#...\n", grid.to_string());
+        assert_eq!(&'.', grid.get(0, 0));
+
+        grid.remove(-2, 1);
+        assert_eq!("#\n", grid.to_string());
+        grid.remove(1, -1);
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn it_iterates_in_row_major_order() {
+        let mut grid = SparseGrid::new(0);
+        grid.insert(5, 0, 1);
+        grid.insert(-5, 0, 2);
+        *grid.get_mut(0, -1) += 3;
+        assert_eq!(
+            vec![((0, -1), &3), ((-5, 0), &2), ((5, 0), &1)],
+            grid.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_converts_to_and_from_dense_grids() {
+        let dense = Grid2D::from_lines(".#.\n..#".lines());
+        let mut sparse = SparseGrid::from_grid(&dense, '.');
+        assert_eq!(2, sparse.len());
+        assert_eq!(".#.\n..#\n", sparse.to_string());
+        assert_eq!(dense, sparse.to_grid());
+
+        // removing cells never shrinks the bounds inside the original grid, but new cells can
+        // still grow them
+        sparse.remove(1, 0);
+        sparse.insert(-1, 0, '#');
+        assert_eq!("#...\n...#", sparse.to_grid().to_string());
+
+        let blank = Grid2D::from_lines("...\n...".lines());
+        assert_eq!(blank, SparseGrid::from_grid(&blank, '.').to_grid());
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt::{Debug, Display, Write};
+
+use super::Grid2D;
+
+/// A read-only window onto a [`Grid2D`] that can be transposed, rotated, flipped or narrowed to a
+/// rectangle without copying any cells.
+///
+/// The cell at `(x, y)` in the view lives at `origin + x * x_step + y * y_step` in the grid's
+/// storage, so every transform is just a change to those three numbers.
+pub struct GridView<'a, T> {
+    cells: &'a [T],
+    width: usize,
+    height: usize,
+    origin: isize,
+    x_step: isize,
+    y_step: isize,
+}
+
+// derived impls would needlessly require `T: Clone`
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Grid2D<T> {
+    /// Views the whole grid, ready to be transformed.
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            cells: &self.cells,
+            width: self.width,
+            height: self.height,
+            origin: 0,
+            x_step: 1,
+            y_step: self.stride as isize,
+        }
+    }
+
+    /// Views the `width` by `height` rectangle whose top left corner is at `(x, y)`, panicking if
+    /// it doesn't fit inside the grid.
+    pub fn sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> GridView<'_, T> {
+        self.view().sub_grid(x, y, width, height)
+    }
+}
+
+impl<T> Grid2D<T>
+where
+    T: Clone,
+{
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        self.view().rotate_cw().to_grid()
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.view().rotate_ccw().to_grid()
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_h(&self) -> Self {
+        self.view().flip_h().to_grid()
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_v(&self) -> Self {
+        self.view().flip_v().to_grid()
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> isize {
+        self.origin + x as isize * self.x_step + y as isize * self.y_step
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.cells.get(self.index(x, y) as usize)
+    }
+
+    /// Iterates over row `row_index`, yielding nothing if it's out of bounds.
+    pub fn row_iterator(&self, row_index: usize) -> StridedIterator<'a, T> {
+        let remaining = if row_index < self.height {
+            self.width
+        } else {
+            0
+        };
+
+        StridedIterator {
+            cells: self.cells,
+            index: self.index(0, row_index),
+            step: self.x_step,
+            remaining,
+        }
+    }
+
+    /// Iterates down column `column_index`, yielding nothing if it's out of bounds.
+    pub fn col_iterator(&self, column_index: usize) -> StridedIterator<'a, T> {
+        let remaining = if column_index < self.width {
+            self.height
+        } else {
+            0
+        };
+
+        StridedIterator {
+            cells: self.cells,
+            index: self.index(column_index, 0),
+            step: self.y_step,
+            remaining,
+        }
+    }
+
+    /// Iterates over every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.height).flat_map(move |y| view.row_iterator(y))
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            x_step: self.y_step,
+            y_step: self.x_step,
+            ..self
+        }
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().flip_h()
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().flip_v()
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_h(self) -> Self {
+        Self {
+            origin: self.index(self.width.saturating_sub(1), 0),
+            x_step: -self.x_step,
+            ..self
+        }
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_v(self) -> Self {
+        Self {
+            origin: self.index(0, self.height.saturating_sub(1)),
+            y_step: -self.y_step,
+            ..self
+        }
+    }
+
+    /// Narrows the view to the `width` by `height` rectangle whose top left corner is at
+    /// `(x, y)`, panicking if it doesn't fit inside the view.
+    pub fn sub_grid(self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        assert!(
+            x + width <= self.width && y + height <= self.height,
+            "{width}x{height} sub-grid at ({x}, {y}) doesn't fit in a {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        Self {
+            width,
+            height,
+            origin: self.index(x, y),
+            ..self
+        }
+    }
+
+    /// Copies the viewed cells into a grid of their own.
+    pub fn to_grid(self) -> Grid2D<T>
+    where
+        T: Clone,
+    {
+        Grid2D {
+            height: self.height,
+            width: self.width,
+            stride: self.width,
+            cells: self.iter().cloned().collect(),
+        }
+    }
+}
+
+impl<T, U> PartialEq<GridView<'_, U>> for GridView<'_, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &GridView<'_, U>) -> bool {
+        self.width == other.width && self.height == other.height && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Display for GridView<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for item in self.row_iterator(y) {
+                f.write_fmt(format_args!("{item}"))?;
+            }
+
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Debug for GridView<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries((0..self.height).map(|y| self.row_iterator(y).collect::<Vec<_>>()))
+            .finish()
+    }
+}
+
+/// Walks a line of cells through a [`GridView`], in whichever direction the view runs.
+pub struct StridedIterator<'a, T> {
+    cells: &'a [T],
+    index: isize,
+    step: isize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for StridedIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let item = &self.cells[self.index as usize];
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.index += self.step;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+
+    fn grid() -> Grid2D<char> {
+        Grid2D::from_lines("abc\ndef".lines())
+    }
+
+    #[test]
+    fn it_transforms_views_without_copying() {
+        let grid = grid();
+        let view = grid.view();
+        assert_eq!("ad\nbe\ncf\n", view.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", view.rotate_cw().to_string());
+        assert_eq!("cf\nbe\nad\n", view.rotate_ccw().to_string());
+        assert_eq!("cba\nfed\n", view.flip_h().to_string());
+        assert_eq!("def\nabc\n", view.flip_v().to_string());
+        assert_eq!(view, view.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+    }
+
+    #[test]
+    fn it_copies_transformed_grids() {
+        let grid = grid();
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_cw().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_ccw().to_string());
+        assert_eq!("cba\nfed", grid.flip_h().to_string());
+        assert_eq!("def\nabc", grid.flip_v().to_string());
+    }
+
+    #[test]
+    fn it_views_and_compares_sub_grids() {
+        let grid = Grid2D::from_lines("abab\ncdcd\nxyxy".lines());
+        let left = grid.sub_grid(0, 0, 2, 2);
+        assert_eq!("ab\ncd\n", left.to_string());
+        assert_eq!(left, grid.sub_grid(2, 0, 2, 2));
+        assert_ne!(left, grid.sub_grid(1, 0, 2, 2));
+        assert_eq!("a\nb\n", left.rotate_cw().sub_grid(1, 0, 1, 2).to_string());
+        assert_eq!(Some(&'y'), grid.sub_grid(1, 1, 3, 2).get(0, 1));
+        assert_eq!(None, left.get(2, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_rejects_sub_grids_that_overflow() {
+        grid().sub_grid(2, 0, 2, 1);
+    }
+}